@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_account::Signature;
+use snarkvm_console_types::Boolean;
+
+impl<N: Network> Value<N> {
+    /// Returns the literal, if the value is a plaintext literal.
+    pub fn as_literal(&self) -> Result<&Literal<N>> {
+        match self {
+            Self::Plaintext(Plaintext::Literal(literal, _)) => Ok(literal),
+            _ => bail!("Expected a literal value, found '{self}'"),
+        }
+    }
+
+    /// Returns the `signature`, if the value is a `signature` literal.
+    pub fn as_signature(&self) -> Result<Signature<N>> {
+        match self.as_literal()? {
+            Literal::Signature(signature) => Ok(**signature),
+            literal => bail!("Expected a 'signature' literal, found '{literal}'"),
+        }
+    }
+}
+
+macro_rules! impl_as_literal {
+    ($(($method:ident, $variant:ident, $type:ty, $type_name:literal)),+ $(,)?) => {
+        impl<N: Network> Value<N> {
+            $(
+                #[doc = concat!("Returns the `", $type_name, "`, if the value is a `", $type_name, "` literal.")]
+                pub fn $method(&self) -> Result<$type> {
+                    match self.as_literal()? {
+                        Literal::$variant(value) => Ok(value.clone()),
+                        literal => bail!("Expected a '{}' literal, found '{literal}'", $type_name),
+                    }
+                }
+            )+
+        }
+    };
+}
+
+impl_as_literal!(
+    (as_address, Address, Address<N>, "address"),
+    (as_boolean, Boolean, Boolean<N>, "boolean"),
+    (as_field, Field, Field<N>, "field"),
+    (as_group, Group, Group<N>, "group"),
+    (as_i8, I8, I8<N>, "i8"),
+    (as_i16, I16, I16<N>, "i16"),
+    (as_i32, I32, I32<N>, "i32"),
+    (as_i64, I64, I64<N>, "i64"),
+    (as_i128, I128, I128<N>, "i128"),
+    (as_u8, U8, U8<N>, "u8"),
+    (as_u16, U16, U16<N>, "u16"),
+    (as_u32, U32, U32<N>, "u32"),
+    (as_u64, U64, U64<N>, "u64"),
+    (as_u128, U128, U128<N>, "u128"),
+    (as_scalar, Scalar, Scalar<N>, "scalar"),
+    (as_string, String, StringType<N>, "string"),
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_as_literal() {
+        // Ensure a field is extracted.
+        let value = Value::<CurrentNetwork>::from_str("19field").unwrap();
+        assert_eq!(value.as_field().unwrap(), Field::from_u64(19));
+        // Ensure a u64 is extracted.
+        let value = Value::<CurrentNetwork>::from_str("100u64").unwrap();
+        assert_eq!(*value.as_u64().unwrap(), 100);
+
+        // Ensure a type mismatch is rejected.
+        let error = value.as_field().unwrap_err();
+        assert!(error.to_string().contains("Expected a 'field' literal, found '100u64'"), "{error}");
+        assert!(value.as_u32().is_err());
+        // Ensure a non-literal value is rejected.
+        let value = Value::<CurrentNetwork>::from_str("{ amount: 100u64 }").unwrap();
+        assert!(value.as_literal().is_err());
+        assert!(value.as_u64().is_err());
+    }
+}
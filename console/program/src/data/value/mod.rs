@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod as_literal;
 mod bytes;
 mod equal;
 mod find;
@@ -27,7 +27,11 @@ impl<N: Network> FromBytes for Request<N> {
         // Read the signer.
         let signer = FromBytes::read_le(&mut reader)?;
         // Read the network ID.
-        let network_id = FromBytes::read_le(&mut reader)?;
+        let network_id: U16<N> = FromBytes::read_le(&mut reader)?;
+        // Ensure the request is for this network.
+        if *network_id != N::ID {
+            return Err(error(format!("Request is for a different network (expected {}, found {network_id})", N::ID)));
+        }
         // Read the program ID.
         let program_id = FromBytes::read_le(&mut reader)?;
         // Read the function name.
@@ -116,6 +120,7 @@ impl<N: Network> ToBytes for Request<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use snarkvm_console_network::MainnetV0;
 
     #[test]
     fn test_bytes() {
@@ -127,4 +132,21 @@ mod tests {
             assert_eq!(expected, Request::read_le(&expected_bytes[..]).unwrap());
         }
     }
+
+    #[test]
+    fn test_bytes_rejects_different_network() {
+        let mut rng = TestRng::default();
+
+        for request in test_helpers::sample_requests(&mut rng).into_iter() {
+            // Overwrite the network ID, which follows the version and signer.
+            let mut bytes = request.to_bytes_le().unwrap();
+            let offset = 1 + request.signer().to_bytes_le().unwrap().len();
+            let network_id = MainnetV0::ID.wrapping_add(1);
+            bytes[offset..offset + 2].copy_from_slice(&network_id.to_le_bytes());
+
+            // Ensure the request is rejected with an error.
+            let error = Request::<MainnetV0>::read_le(&bytes[..]).unwrap_err();
+            assert!(error.to_string().starts_with("Request is for a different network"));
+        }
+    }
 }
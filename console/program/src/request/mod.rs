@@ -20,6 +20,7 @@ mod serialize;
 mod sign;
 mod string;
 mod verify;
+pub use verify::RequestVerifyError;
 
 use crate::{compute_function_id, Identifier, Plaintext, ProgramID, Record, Value, ValueType};
 use snarkvm_console_account::{Address, ComputeKey, GraphKey, PrivateKey, Signature, ViewKey};
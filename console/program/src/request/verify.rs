@@ -14,27 +14,62 @@
 
 use super::*;
 
+/// A verification failure of a request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RequestVerifyError {
+    /// The transition commitment is not the hash of the transition view key.
+    InvalidTransitionCommitment,
+    /// The request could not be checked, i.e. the function ID or transition commitment could not be computed.
+    MalformedRequest(String),
+    /// The input at the given index does not match its input ID.
+    MalformedInput(usize, String),
+    /// The record input at the given index does not match its serial number.
+    InvalidSerialNumber(usize),
+    /// The signature of the request is invalid.
+    InvalidSignature,
+}
+
+impl Display for RequestVerifyError {
+    /// Prints the verification failure as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidTransitionCommitment => write!(f, "Invalid transition commitment"),
+            Self::MalformedRequest(error) => write!(f, "Malformed request - {error}"),
+            Self::MalformedInput(index, error) => write!(f, "Malformed input {index} - {error}"),
+            Self::InvalidSerialNumber(index) => write!(f, "Invalid serial number for input {index}"),
+            Self::InvalidSignature => write!(f, "Invalid signature"),
+        }
+    }
+}
+
+impl std::error::Error for RequestVerifyError {}
+
 impl<N: Network> Request<N> {
     /// Returns `true` if the request is valid, and `false` otherwise.
+    /// Note: See `Request::verify_detailed` for the cause of a verification failure.
+    pub fn verify(&self, input_types: &[ValueType<N>], is_root: bool) -> bool {
+        match self.verify_detailed(input_types, is_root) {
+            Ok(()) => true,
+            Err(error) => {
+                eprintln!("Request verification failed: {error}");
+                false
+            }
+        }
+    }
+
+    /// Returns `Ok(())` if the request is valid, and the cause of the verification failure otherwise.
     ///
     /// Verifies (challenge == challenge') && (address == address') && (serial_numbers == serial_numbers') where:
     ///     challenge' := HashToScalar(r * G, pk_sig, pr_sig, signer, \[tvk, tcm, function ID, input IDs\])
-    pub fn verify(&self, input_types: &[ValueType<N>], is_root: bool) -> bool {
+    pub fn verify_detailed(&self, input_types: &[ValueType<N>], is_root: bool) -> Result<(), RequestVerifyError> {
         // Verify the transition public key, transition view key, and transition commitment are well-formed.
         {
             // Compute the transition commitment `tcm` as `Hash(tvk)`.
-            match N::hash_psd2(&[self.tvk]) {
-                Ok(tcm) => {
-                    // Ensure the computed transition commitment matches.
-                    if tcm != self.tcm {
-                        eprintln!("Invalid transition commitment in request.");
-                        return false;
-                    }
-                }
-                Err(error) => {
-                    eprintln!("Failed to compute transition commitment in request verification: {error}");
-                    return false;
-                }
+            let tcm =
+                N::hash_psd2(&[self.tvk]).map_err(|error| RequestVerifyError::MalformedRequest(error.to_string()))?;
+            // Ensure the computed transition commitment matches.
+            if tcm != self.tcm {
+                return Err(RequestVerifyError::InvalidTransitionCommitment);
             }
         }
 
@@ -44,13 +79,8 @@ impl<N: Network> Request<N> {
         let response = self.signature.response();
 
         // Compute the function ID.
-        let function_id = match compute_function_id(&self.network_id, &self.program_id, &self.function_name) {
-            Ok(function_id) => function_id,
-            Err(error) => {
-                eprintln!("Failed to construct the function ID: {error}");
-                return false;
-            }
-        };
+        let function_id = compute_function_id(&self.network_id, &self.program_id, &self.function_name)
+            .map_err(|error| RequestVerifyError::MalformedRequest(error.to_string()))?;
 
         // Compute the 'is_root' field.
         let is_root = if is_root { Field::<N>::one() } else { Field::<N>::zero() };
@@ -62,148 +92,161 @@ impl<N: Network> Request<N> {
         message.push(function_id);
         message.push(is_root);
 
-        if let Err(error) = self.input_ids.iter().zip_eq(&self.inputs).zip_eq(input_types).enumerate().try_for_each(
-            |(index, ((input_id, input), input_type))| {
-                match input_id {
-                    // A constant input is hashed (using `tcm`) to a field element.
-                    InputID::Constant(input_hash) => {
-                        // Ensure the input is a plaintext.
-                        ensure!(matches!(input, Value::Plaintext(..)), "Expected a plaintext input");
-
-                        // Construct the (console) input index as a field element.
-                        let index = Field::from_u16(u16::try_from(index).or_halt_with::<N>("Input index exceeds u16"));
-                        // Construct the preimage as `(function ID || input || tcm || index)`.
-                        let mut preimage = Vec::new();
-                        preimage.push(function_id);
-                        preimage.extend(input.to_fields()?);
-                        preimage.push(self.tcm);
-                        preimage.push(index);
-                        // Hash the input to a field element.
-                        let candidate_hash = N::hash_psd8(&preimage)?;
-                        // Ensure the input hash matches.
-                        ensure!(*input_hash == candidate_hash, "Expected a constant input with the same hash");
-
-                        // Add the input hash to the message.
-                        message.push(candidate_hash);
-                    }
-                    // A public input is hashed (using `tcm`) to a field element.
-                    InputID::Public(input_hash) => {
-                        // Ensure the input is a plaintext.
-                        ensure!(matches!(input, Value::Plaintext(..)), "Expected a plaintext input");
-
-                        // Construct the (console) input index as a field element.
-                        let index = Field::from_u16(u16::try_from(index).or_halt_with::<N>("Input index exceeds u16"));
-                        // Construct the preimage as `(function ID || input || tcm || index)`.
-                        let mut preimage = Vec::new();
-                        preimage.push(function_id);
-                        preimage.extend(input.to_fields()?);
-                        preimage.push(self.tcm);
-                        preimage.push(index);
-                        // Hash the input to a field element.
-                        let candidate_hash = N::hash_psd8(&preimage)?;
-                        // Ensure the input hash matches.
-                        ensure!(*input_hash == candidate_hash, "Expected a public input with the same hash");
-
-                        // Add the input hash to the message.
-                        message.push(candidate_hash);
-                    }
-                    // A private input is encrypted (using `tvk`) and hashed to a field element.
-                    InputID::Private(input_hash) => {
+        // Checks the input at the given index against its input ID, and adds the input ID to the message.
+        let mut check_input = |index: usize, input_id: &InputID<N>, input: &Value<N>, input_type: &ValueType<N>| {
+            match input_id {
+                // A constant input is hashed (using `tcm`) to a field element.
+                InputID::Constant(input_hash) => {
+                    // Ensure the input is a plaintext.
+                    ensure!(matches!(input, Value::Plaintext(..)), "Expected a plaintext input");
+
+                    // Construct the (console) input index as a field element.
+                    let index = Field::from_u16(u16::try_from(index).or_halt_with::<N>("Input index exceeds u16"));
+                    // Construct the preimage as `(function ID || input || tcm || index)`.
+                    let mut preimage = Vec::new();
+                    preimage.push(function_id);
+                    preimage.extend(input.to_fields()?);
+                    preimage.push(self.tcm);
+                    preimage.push(index);
+                    // Hash the input to a field element.
+                    let candidate_hash = N::hash_psd8(&preimage)?;
+                    // Ensure the input hash matches.
+                    ensure!(*input_hash == candidate_hash, "Expected a constant input with the same hash");
+
+                    // Add the input hash to the message.
+                    message.push(candidate_hash);
+                }
+                // A public input is hashed (using `tcm`) to a field element.
+                InputID::Public(input_hash) => {
+                    // Ensure the input is a plaintext.
+                    ensure!(matches!(input, Value::Plaintext(..)), "Expected a plaintext input");
+
+                    // Construct the (console) input index as a field element.
+                    let index = Field::from_u16(u16::try_from(index).or_halt_with::<N>("Input index exceeds u16"));
+                    // Construct the preimage as `(function ID || input || tcm || index)`.
+                    let mut preimage = Vec::new();
+                    preimage.push(function_id);
+                    preimage.extend(input.to_fields()?);
+                    preimage.push(self.tcm);
+                    preimage.push(index);
+                    // Hash the input to a field element.
+                    let candidate_hash = N::hash_psd8(&preimage)?;
+                    // Ensure the input hash matches.
+                    ensure!(*input_hash == candidate_hash, "Expected a public input with the same hash");
+
+                    // Add the input hash to the message.
+                    message.push(candidate_hash);
+                }
+                // A private input is encrypted (using `tvk`) and hashed to a field element.
+                InputID::Private(input_hash) => {
+                    // Ensure the input is a plaintext.
+                    ensure!(matches!(input, Value::Plaintext(..)), "Expected a plaintext input");
+
+                    // Construct the (console) input index as a field element.
+                    let index = Field::from_u16(u16::try_from(index).or_halt_with::<N>("Input index exceeds u16"));
+                    // Compute the input view key as `Hash(function ID || tvk || index)`.
+                    let input_view_key = N::hash_psd4(&[function_id, self.tvk, index])?;
+                    // Compute the ciphertext.
+                    let ciphertext = match &input {
+                        Value::Plaintext(plaintext) => plaintext.encrypt_symmetric(input_view_key)?,
                         // Ensure the input is a plaintext.
-                        ensure!(matches!(input, Value::Plaintext(..)), "Expected a plaintext input");
-
-                        // Construct the (console) input index as a field element.
-                        let index = Field::from_u16(u16::try_from(index).or_halt_with::<N>("Input index exceeds u16"));
-                        // Compute the input view key as `Hash(function ID || tvk || index)`.
-                        let input_view_key = N::hash_psd4(&[function_id, self.tvk, index])?;
-                        // Compute the ciphertext.
-                        let ciphertext = match &input {
-                            Value::Plaintext(plaintext) => plaintext.encrypt_symmetric(input_view_key)?,
-                            // Ensure the input is a plaintext.
-                            Value::Record(..) => bail!("Expected a plaintext input, found a record input"),
-                            Value::Future(..) => bail!("Expected a plaintext input, found a future input"),
-                        };
-                        // Hash the ciphertext to a field element.
-                        let candidate_hash = N::hash_psd8(&ciphertext.to_fields()?)?;
-                        // Ensure the input hash matches.
-                        ensure!(*input_hash == candidate_hash, "Expected a private input with the same commitment");
-
-                        // Add the input hash to the message.
-                        message.push(candidate_hash);
-                    }
-                    // A record input is computed to its serial number.
-                    InputID::Record(commitment, gamma, serial_number, tag) => {
-                        // Retrieve the record.
-                        let record = match &input {
-                            Value::Record(record) => record,
-                            // Ensure the input is a record.
-                            Value::Plaintext(..) => bail!("Expected a record input, found a plaintext input"),
-                            Value::Future(..) => bail!("Expected a record input, found a future input"),
-                        };
-                        // Retrieve the record name.
-                        let record_name = match input_type {
-                            ValueType::Record(record_name) => record_name,
-                            // Ensure the input type is a record.
-                            _ => bail!("Expected a record type at input {index}"),
-                        };
-                        // Ensure the record belongs to the signer.
-                        ensure!(**record.owner() == self.signer, "Input record does not belong to the signer");
-
-                        // Compute the record commitment.
-                        let candidate_cm = record.to_commitment(&self.program_id, record_name)?;
-                        // Ensure the commitment matches.
-                        ensure!(*commitment == candidate_cm, "Expected a record input with the same commitment");
-
-                        // Compute the `candidate_sn` from `gamma`.
-                        let candidate_sn = Record::<N, Plaintext<N>>::serial_number_from_gamma(gamma, *commitment)?;
-                        // Ensure the serial number matches.
-                        ensure!(*serial_number == candidate_sn, "Expected a record input with the same serial number");
-
-                        // Compute the generator `H` as `HashToGroup(commitment)`.
-                        let h = N::hash_to_group_psd2(&[N::serial_number_domain(), *commitment])?;
-                        // Compute `h_r` as `(challenge * gamma) + (response * H)`, equivalent to `r * H`.
-                        let h_r = (*gamma * challenge) + (h * response);
-
-                        // Compute the tag as `Hash(sk_tag || commitment)`.
-                        let candidate_tag = N::hash_psd2(&[self.sk_tag, *commitment])?;
-                        // Ensure the tag matches.
-                        ensure!(*tag == candidate_tag, "Expected a record input with the same tag");
-
-                        // Add (`H`, `r * H`, `gamma`, `tag`) to the message.
-                        message.extend([h, h_r, *gamma].iter().map(|point| point.to_x_coordinate()));
-                        message.push(*tag);
-                    }
-                    // An external record input is hashed (using `tvk`) to a field element.
-                    InputID::ExternalRecord(input_hash) => {
+                        Value::Record(..) => bail!("Expected a plaintext input, found a record input"),
+                        Value::Future(..) => bail!("Expected a plaintext input, found a future input"),
+                    };
+                    // Hash the ciphertext to a field element.
+                    let candidate_hash = N::hash_psd8(&ciphertext.to_fields()?)?;
+                    // Ensure the input hash matches.
+                    ensure!(*input_hash == candidate_hash, "Expected a private input with the same commitment");
+
+                    // Add the input hash to the message.
+                    message.push(candidate_hash);
+                }
+                // A record input is computed to its serial number.
+                InputID::Record(commitment, gamma, serial_number, tag) => {
+                    // Retrieve the record.
+                    let record = match &input {
+                        Value::Record(record) => record,
                         // Ensure the input is a record.
-                        ensure!(matches!(input, Value::Record(..)), "Expected a record input");
-
-                        // Construct the (console) input index as a field element.
-                        let index = Field::from_u16(u16::try_from(index).or_halt_with::<N>("Input index exceeds u16"));
-                        // Construct the preimage as `(function ID || input || tvk || index)`.
-                        let mut preimage = Vec::new();
-                        preimage.push(function_id);
-                        preimage.extend(input.to_fields()?);
-                        preimage.push(self.tvk);
-                        preimage.push(index);
-                        // Hash the input to a field element.
-                        let candidate_hash = N::hash_psd8(&preimage)?;
-                        // Ensure the input hash matches.
-                        ensure!(*input_hash == candidate_hash, "Expected a locator input with the same hash");
-
-                        // Add the input hash to the message.
-                        message.push(candidate_hash);
+                        Value::Plaintext(..) => bail!("Expected a record input, found a plaintext input"),
+                        Value::Future(..) => bail!("Expected a record input, found a future input"),
+                    };
+                    // Retrieve the record name.
+                    let record_name = match input_type {
+                        ValueType::Record(record_name) => record_name,
+                        // Ensure the input type is a record.
+                        _ => bail!("Expected a record type at input {index}"),
+                    };
+                    // Ensure the record belongs to the signer.
+                    ensure!(**record.owner() == self.signer, "Input record does not belong to the signer");
+
+                    // Compute the record commitment.
+                    let candidate_cm = record.to_commitment(&self.program_id, record_name)?;
+                    // Ensure the commitment matches.
+                    ensure!(*commitment == candidate_cm, "Expected a record input with the same commitment");
+
+                    // Compute the `candidate_sn` from `gamma`.
+                    let candidate_sn = Record::<N, Plaintext<N>>::serial_number_from_gamma(gamma, *commitment)?;
+                    // Ensure the serial number matches.
+                    if *serial_number != candidate_sn {
+                        return Err(RequestVerifyError::InvalidSerialNumber(index).into());
                     }
+
+                    // Compute the generator `H` as `HashToGroup(commitment)`.
+                    let h = N::hash_to_group_psd2(&[N::serial_number_domain(), *commitment])?;
+                    // Compute `h_r` as `(challenge * gamma) + (response * H)`, equivalent to `r * H`.
+                    let h_r = (*gamma * challenge) + (h * response);
+
+                    // Compute the tag as `Hash(sk_tag || commitment)`.
+                    let candidate_tag = N::hash_psd2(&[self.sk_tag, *commitment])?;
+                    // Ensure the tag matches.
+                    ensure!(*tag == candidate_tag, "Expected a record input with the same tag");
+
+                    // Add (`H`, `r * H`, `gamma`, `tag`) to the message.
+                    message.extend([h, h_r, *gamma].iter().map(|point| point.to_x_coordinate()));
+                    message.push(*tag);
+                }
+                // An external record input is hashed (using `tvk`) to a field element.
+                InputID::ExternalRecord(input_hash) => {
+                    // Ensure the input is a record.
+                    ensure!(matches!(input, Value::Record(..)), "Expected a record input");
+
+                    // Construct the (console) input index as a field element.
+                    let index = Field::from_u16(u16::try_from(index).or_halt_with::<N>("Input index exceeds u16"));
+                    // Construct the preimage as `(function ID || input || tvk || index)`.
+                    let mut preimage = Vec::new();
+                    preimage.push(function_id);
+                    preimage.extend(input.to_fields()?);
+                    preimage.push(self.tvk);
+                    preimage.push(index);
+                    // Hash the input to a field element.
+                    let candidate_hash = N::hash_psd8(&preimage)?;
+                    // Ensure the input hash matches.
+                    ensure!(*input_hash == candidate_hash, "Expected a locator input with the same hash");
+
+                    // Add the input hash to the message.
+                    message.push(candidate_hash);
                 }
-                Ok(())
-            },
-        ) {
-            eprintln!("Request verification failed on input checks: {error}");
-            return false;
+            }
+            Ok::<_, Error>(())
+        };
+
+        for (index, ((input_id, input), input_type)) in
+            self.input_ids.iter().zip_eq(&self.inputs).zip_eq(input_types).enumerate()
+        {
+            // Check the input, and surface a serial number mismatch as its own failure.
+            check_input(index, input_id, input, input_type).map_err(|error| {
+                match error.downcast::<RequestVerifyError>() {
+                    Ok(error) => error,
+                    Err(error) => RequestVerifyError::MalformedInput(index, error.to_string()),
+                }
+            })?;
         }
 
         // Verify the signature.
-        self.signature.verify(&self.signer, &message)
+        match self.signature.verify(&self.signer, &message) {
+            true => Ok(()),
+            false => Err(RequestVerifyError::InvalidSignature),
+        }
     }
 }
 
@@ -272,4 +315,47 @@ mod tests {
             assert!(request.verify(&input_types, is_root));
         }
     }
+
+    #[test]
+    fn test_verify_detailed() {
+        let rng = &mut TestRng::default();
+
+        // Sample a random private key and address.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Construct a program ID and function name.
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let function_name = Identifier::from_str("transfer").unwrap();
+
+        // Construct a public input and a record input.
+        let record_string =
+            format!("{{ owner: {address}.private, token_amount: 100u64.private, _nonce: 0group.public }}");
+        let inputs = [Value::from_str("5u64").unwrap(), Value::from_str(&record_string).unwrap()];
+        let input_types = [ValueType::from_str("u64.public").unwrap(), ValueType::from_str("token.record").unwrap()];
+
+        // Compute the signed request.
+        let request =
+            Request::sign(&private_key, program_id, function_name, inputs.into_iter(), &input_types, None, true, rng)
+                .unwrap();
+        assert_eq!(request.verify_detailed(&input_types, true), Ok(()));
+
+        // Ensure a request signed for a different position in the call graph fails on the signature.
+        assert_eq!(request.verify_detailed(&input_types, false), Err(RequestVerifyError::InvalidSignature));
+
+        // Ensure a mismatched transition commitment is reported.
+        let candidate = Request { tcm: Uniform::rand(rng), ..request.clone() };
+        assert_eq!(candidate.verify_detailed(&input_types, true), Err(RequestVerifyError::InvalidTransitionCommitment));
+
+        // Ensure a modified input is reported as malformed, with its index.
+        let mut candidate = request.clone();
+        candidate.inputs[0] = Value::from_str("6u64").unwrap();
+        assert!(matches!(candidate.verify_detailed(&input_types, true), Err(RequestVerifyError::MalformedInput(0, _))));
+
+        // Ensure a modified serial number is reported, with its index.
+        let mut candidate = request.clone();
+        let InputID::Record(commitment, gamma, _, tag) = candidate.input_ids[1] else { unreachable!() };
+        candidate.input_ids[1] = InputID::Record(commitment, gamma, Uniform::rand(rng), tag);
+        assert_eq!(candidate.verify_detailed(&input_types, true), Err(RequestVerifyError::InvalidSerialNumber(1)));
+    }
 }
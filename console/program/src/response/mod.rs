@@ -12,10 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{compute_function_id, Identifier, ProgramID, Register, Value, ValueType};
+use crate::{compute_function_id, Identifier, Literal, Owner, Plaintext, ProgramID, Register, Value, ValueType};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum OutputID<N: Network> {
     /// The hash of the constant output.
@@ -48,7 +51,11 @@ impl<N: Network> From<(Vec<OutputID<N>>, Vec<Value<N>>)> for Response<N> {
 }
 
 impl<N: Network> Response<N> {
+    /// The minimum number of outputs for which the output IDs are computed in parallel.
+    const PARALLEL_OUTPUTS_THRESHOLD: usize = 8;
+
     /// Initializes a new response.
+    /// Note: If there are sufficiently many outputs, the output IDs are computed in parallel.
     pub fn new(
         network_id: &U16<N>,
         program_id: &ProgramID<N>,
@@ -60,158 +67,209 @@ impl<N: Network> Response<N> {
         output_types: &[ValueType<N>],
         output_operands: &[Option<Register<N>>],
     ) -> Result<Self> {
+        // Determine whether to compute the output IDs in parallel.
+        let parallel = outputs.len() >= Self::PARALLEL_OUTPUTS_THRESHOLD;
+        // Initialize the response.
+        Self::new_internal(
+            network_id,
+            program_id,
+            function_name,
+            num_inputs,
+            tvk,
+            tcm,
+            outputs,
+            output_types,
+            output_operands,
+            parallel,
+        )
+    }
+
+    /// Initializes a new response, computing the output IDs in parallel if `parallel` is `true`.
+    fn new_internal(
+        network_id: &U16<N>,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        num_inputs: usize,
+        tvk: &Field<N>,
+        tcm: &Field<N>,
+        outputs: Vec<Value<N>>,
+        output_types: &[ValueType<N>],
+        output_operands: &[Option<Register<N>>],
+        parallel: bool,
+    ) -> Result<Self> {
+        // Ensure the number of outputs, output types, and output registers match.
+        let num_outputs = output_types.len();
+        ensure!(
+            outputs.len() == num_outputs && output_operands.len() == num_outputs,
+            "Expected {num_outputs} outputs, found {} outputs and {} output registers",
+            outputs.len(),
+            output_operands.len()
+        );
+
         // Compute the function ID.
         let function_id = compute_function_id(network_id, program_id, function_name)?;
 
-        // Compute the output IDs.
-        let output_ids = outputs
-            .iter()
-            .zip_eq(output_types)
-            .zip_eq(output_operands)
-            .enumerate()
-            .map(|(index, ((output, output_type), output_register))| {
-                match output_type {
-                    // For a constant output, compute the hash (using `tcm`) of the output.
-                    ValueType::Constant(..) => {
-                        // Ensure the output is a plaintext.
-                        ensure!(matches!(output, Value::Plaintext(..)), "Expected a plaintext output");
-
-                        // Construct the (console) output index as a field element.
-                        let index = Field::from_u16(
-                            u16::try_from(num_inputs + index).or_halt_with::<N>("Output index exceeds u16"),
-                        );
-                        // Construct the preimage as `(function ID || output || tcm || index)`.
-                        let mut preimage = Vec::new();
-                        preimage.push(function_id);
-                        preimage.extend(output.to_fields()?);
-                        preimage.push(*tcm);
-                        preimage.push(index);
-                        // Hash the output to a field element.
-                        let output_hash = N::hash_psd8(&preimage)?;
-
-                        // Return the output ID.
-                        Ok(OutputID::Constant(output_hash))
-                    }
-                    // For a public output, compute the hash (using `tcm`) of the output.
-                    ValueType::Public(..) => {
-                        // Ensure the output is a plaintext.
-                        ensure!(matches!(output, Value::Plaintext(..)), "Expected a plaintext output");
-
-                        // Construct the (console) output index as a field element.
-                        let index = Field::from_u16(
-                            u16::try_from(num_inputs + index).or_halt_with::<N>("Output index exceeds u16"),
-                        );
-                        // Construct the preimage as `(function ID || output || tcm || index)`.
-                        let mut preimage = Vec::new();
-                        preimage.push(function_id);
-                        preimage.extend(output.to_fields()?);
-                        preimage.push(*tcm);
-                        preimage.push(index);
-                        // Hash the output to a field element.
-                        let output_hash = N::hash_psd8(&preimage)?;
-
-                        // Return the output ID.
-                        Ok(OutputID::Public(output_hash))
-                    }
-                    // For a private output, compute the ciphertext (using `tvk`) and hash the ciphertext.
-                    ValueType::Private(..) => {
+        // A helper to compute the output ID of an output.
+        type Tuple<'a, N> = (usize, ((&'a Value<N>, &'a ValueType<N>), &'a Option<Register<N>>));
+        let to_output_id = |(index, ((output, output_type), output_register)): Tuple<'_, N>| -> Result<OutputID<N>> {
+            match output_type {
+                // For a constant output, compute the hash (using `tcm`) of the output.
+                ValueType::Constant(..) => {
+                    // Ensure the output is a plaintext.
+                    ensure!(matches!(output, Value::Plaintext(..)), "Expected a plaintext output");
+
+                    // Construct the (console) output index as a field element.
+                    let index = Field::from_u16(
+                        u16::try_from(num_inputs + index).or_halt_with::<N>("Output index exceeds u16"),
+                    );
+                    // Construct the preimage as `(function ID || output || tcm || index)`.
+                    let mut preimage = Vec::new();
+                    preimage.push(function_id);
+                    preimage.extend(output.to_fields()?);
+                    preimage.push(*tcm);
+                    preimage.push(index);
+                    // Hash the output to a field element.
+                    let output_hash = N::hash_psd8(&preimage)?;
+
+                    // Return the output ID.
+                    Ok(OutputID::Constant(output_hash))
+                }
+                // For a public output, compute the hash (using `tcm`) of the output.
+                ValueType::Public(..) => {
+                    // Ensure the output is a plaintext.
+                    ensure!(matches!(output, Value::Plaintext(..)), "Expected a plaintext output");
+
+                    // Construct the (console) output index as a field element.
+                    let index = Field::from_u16(
+                        u16::try_from(num_inputs + index).or_halt_with::<N>("Output index exceeds u16"),
+                    );
+                    // Construct the preimage as `(function ID || output || tcm || index)`.
+                    let mut preimage = Vec::new();
+                    preimage.push(function_id);
+                    preimage.extend(output.to_fields()?);
+                    preimage.push(*tcm);
+                    preimage.push(index);
+                    // Hash the output to a field element.
+                    let output_hash = N::hash_psd8(&preimage)?;
+
+                    // Return the output ID.
+                    Ok(OutputID::Public(output_hash))
+                }
+                // For a private output, compute the ciphertext (using `tvk`) and hash the ciphertext.
+                ValueType::Private(..) => {
+                    // Ensure the output is a plaintext.
+                    ensure!(matches!(output, Value::Plaintext(..)), "Expected a plaintext output");
+                    // Construct the (console) output index as a field element.
+                    let index = Field::from_u16(
+                        u16::try_from(num_inputs + index).or_halt_with::<N>("Output index exceeds u16"),
+                    );
+                    // Compute the output view key as `Hash(function ID || tvk || index)`.
+                    let output_view_key = N::hash_psd4(&[function_id, *tvk, index])?;
+                    // Compute the ciphertext.
+                    let ciphertext = match &output {
+                        Value::Plaintext(plaintext) => plaintext.encrypt_symmetric(output_view_key)?,
                         // Ensure the output is a plaintext.
-                        ensure!(matches!(output, Value::Plaintext(..)), "Expected a plaintext output");
-                        // Construct the (console) output index as a field element.
-                        let index = Field::from_u16(
-                            u16::try_from(num_inputs + index).or_halt_with::<N>("Output index exceeds u16"),
-                        );
-                        // Compute the output view key as `Hash(function ID || tvk || index)`.
-                        let output_view_key = N::hash_psd4(&[function_id, *tvk, index])?;
-                        // Compute the ciphertext.
-                        let ciphertext = match &output {
-                            Value::Plaintext(plaintext) => plaintext.encrypt_symmetric(output_view_key)?,
-                            // Ensure the output is a plaintext.
-                            Value::Record(..) => bail!("Expected a plaintext output, found a record output"),
-                            Value::Future(..) => bail!("Expected a plaintext output, found a future output"),
-                        };
-                        // Hash the ciphertext to a field element.
-                        let output_hash = N::hash_psd8(&ciphertext.to_fields()?)?;
-                        // Return the output ID.
-                        Ok(OutputID::Private(output_hash))
-                    }
-                    // For a record output, compute the record commitment, and encrypt the record (using `tvk`).
-                    ValueType::Record(record_name) => {
-                        // Retrieve the record.
-                        let record = match &output {
-                            Value::Record(record) => record,
-                            // Ensure the input is a record.
-                            Value::Plaintext(..) => bail!("Expected a record output, found a plaintext output"),
-                            Value::Future(..) => bail!("Expected a record output, found a future output"),
-                        };
-
-                        // Retrieve the output register.
-                        let output_register = match output_register {
-                            Some(output_register) => output_register,
-                            None => bail!("Expected a register to be paired with a record output"),
-                        };
-
-                        // Compute the record commitment.
-                        let commitment = record.to_commitment(program_id, record_name)?;
-
-                        // Construct the (console) output index as a field element.
-                        let index = Field::from_u64(output_register.locator());
-                        // Compute the encryption randomizer as `HashToScalar(tvk || index)`.
-                        let randomizer = N::hash_to_scalar_psd2(&[*tvk, index])?;
-
-                        // Encrypt the record, using the randomizer.
-                        let encrypted_record = record.encrypt(randomizer)?;
-                        // Compute the record checksum, as the hash of the encrypted record.
-                        let checksum = N::hash_bhp1024(&encrypted_record.to_bits_le())?;
-
-                        // Return the output ID.
-                        Ok(OutputID::Record(commitment, checksum))
-                    }
-                    // For a locator output, compute the hash (using `tvk`) of the output.
-                    ValueType::ExternalRecord(..) => {
-                        // Ensure the output is a record.
-                        ensure!(matches!(output, Value::Record(..)), "Expected a record output");
-
-                        // Construct the (console) output index as a field element.
-                        let index = Field::from_u16(
-                            u16::try_from(num_inputs + index).or_halt_with::<N>("Output index exceeds u16"),
-                        );
-                        // Construct the preimage as `(function ID || output || tvk || index)`.
-                        let mut preimage = Vec::new();
-                        preimage.push(function_id);
-                        preimage.extend(output.to_fields()?);
-                        preimage.push(*tvk);
-                        preimage.push(index);
-                        // Hash the output to a field element.
-                        let output_hash = N::hash_psd8(&preimage)?;
-
-                        // Return the output ID.
-                        Ok(OutputID::ExternalRecord(output_hash))
-                    }
-                    // For a future output, compute the hash (using `tcm`) of the output.
-                    ValueType::Future(..) => {
-                        // Ensure the output is a future.
-                        ensure!(matches!(output, Value::Future(..)), "Expected a future output");
-
-                        // Construct the (console) output index as a field element.
-                        let index = Field::from_u16(
-                            u16::try_from(num_inputs + index).or_halt_with::<N>("Output index exceeds u16"),
-                        );
-                        // Construct the preimage as `(function ID || output || tcm || index)`.
-                        let mut preimage = Vec::new();
-                        preimage.push(function_id);
-                        preimage.extend(output.to_fields()?);
-                        preimage.push(*tcm);
-                        preimage.push(index);
-                        // Hash the output to a field element.
-                        let output_hash = N::hash_psd8(&preimage)?;
-
-                        // Return the output ID.
-                        Ok(OutputID::Future(output_hash))
+                        Value::Record(..) => bail!("Expected a plaintext output, found a record output"),
+                        Value::Future(..) => bail!("Expected a plaintext output, found a future output"),
+                    };
+                    // Hash the ciphertext to a field element.
+                    let output_hash = N::hash_psd8(&ciphertext.to_fields()?)?;
+                    // Return the output ID.
+                    Ok(OutputID::Private(output_hash))
+                }
+                // For a record output, compute the record commitment, and encrypt the record (using `tvk`).
+                ValueType::Record(record_name) => {
+                    // Retrieve the record.
+                    let record = match &output {
+                        Value::Record(record) => record,
+                        // Ensure the input is a record.
+                        Value::Plaintext(..) => bail!("Expected a record output, found a plaintext output"),
+                        Value::Future(..) => bail!("Expected a record output, found a future output"),
+                    };
+
+                    // Ensure the record owner is an address.
+                    if !matches!(
+                        record.owner(),
+                        Owner::Public(..) | Owner::Private(Plaintext::Literal(Literal::Address(..), ..))
+                    ) {
+                        bail!("Record owner must be an address")
                     }
+
+                    // Retrieve the output register.
+                    let output_register = match output_register {
+                        Some(output_register) => output_register,
+                        None => bail!("Expected a register to be paired with a record output"),
+                    };
+
+                    // Compute the record commitment.
+                    let commitment = record.to_commitment(program_id, record_name)?;
+
+                    // Construct the (console) output index as a field element.
+                    let index = Field::from_u64(output_register.locator());
+                    // Compute the encryption randomizer as `HashToScalar(tvk || index)`.
+                    let randomizer = N::hash_to_scalar_psd2(&[*tvk, index])?;
+
+                    // Encrypt the record, using the randomizer.
+                    let encrypted_record = record.encrypt(randomizer)?;
+                    // Compute the record checksum, as the hash of the encrypted record.
+                    let checksum = N::hash_bhp1024(&encrypted_record.to_bits_le())?;
+
+                    // Return the output ID.
+                    Ok(OutputID::Record(commitment, checksum))
                 }
-            })
-            .collect::<Result<Vec<_>>>()?;
+                // For a locator output, compute the hash (using `tvk`) of the output.
+                ValueType::ExternalRecord(..) => {
+                    // Ensure the output is a record.
+                    ensure!(matches!(output, Value::Record(..)), "Expected a record output");
+
+                    // Construct the (console) output index as a field element.
+                    let index = Field::from_u16(
+                        u16::try_from(num_inputs + index).or_halt_with::<N>("Output index exceeds u16"),
+                    );
+                    // Construct the preimage as `(function ID || output || tvk || index)`.
+                    let mut preimage = Vec::new();
+                    preimage.push(function_id);
+                    preimage.extend(output.to_fields()?);
+                    preimage.push(*tvk);
+                    preimage.push(index);
+                    // Hash the output to a field element.
+                    let output_hash = N::hash_psd8(&preimage)?;
+
+                    // Return the output ID.
+                    Ok(OutputID::ExternalRecord(output_hash))
+                }
+                // For a future output, compute the hash (using `tcm`) of the output.
+                ValueType::Future(..) => {
+                    // Ensure the output is a future.
+                    ensure!(matches!(output, Value::Future(..)), "Expected a future output");
+
+                    // Construct the (console) output index as a field element.
+                    let index = Field::from_u16(
+                        u16::try_from(num_inputs + index).or_halt_with::<N>("Output index exceeds u16"),
+                    );
+                    // Construct the preimage as `(function ID || output || tcm || index)`.
+                    let mut preimage = Vec::new();
+                    preimage.push(function_id);
+                    preimage.extend(output.to_fields()?);
+                    preimage.push(*tcm);
+                    preimage.push(index);
+                    // Hash the output to a field element.
+                    let output_hash = N::hash_psd8(&preimage)?;
+
+                    // Return the output ID.
+                    Ok(OutputID::Future(output_hash))
+                }
+            }
+        };
+
+        // Prepare each output with its index, type, and register.
+        let tuples = outputs.iter().zip_eq(output_types).zip_eq(output_operands).enumerate().collect::<Vec<_>>();
+        // Compute the output IDs.
+        // Note: The output IDs are collected in order, so the sequential and parallel paths are identical.
+        let output_ids = match parallel {
+            false => tuples.into_iter().map(&to_output_id).collect::<Result<Vec<_>>>()?,
+            true => cfg_into_iter!(tuples).map(&to_output_id).collect::<Result<Vec<_>>>()?,
+        };
 
         Ok(Self { output_ids, outputs })
     }
@@ -225,4 +283,176 @@ impl<N: Network> Response<N> {
     pub fn outputs(&self) -> &[Value<N>] {
         &self.outputs
     }
+
+    /// Returns the function output at the given index, if it exists.
+    pub fn output(&self, index: usize) -> Option<&Value<N>> {
+        self.outputs.get(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Entry, LiteralType, PlaintextType, Record};
+    use snarkvm_console_network::MainnetV0;
+
+    use indexmap::IndexMap;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parallel_output_ids() {
+        let rng = &mut TestRng::default();
+
+        // Sample the response components.
+        let network_id = U16::new(CurrentNetwork::ID);
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let function_name = Identifier::from_str("transfer").unwrap();
+        let tvk = Field::rand(rng);
+        let tcm = Field::rand(rng);
+
+        // Sample many outputs, of each plaintext visibility.
+        let num_outputs = 3 * Response::<CurrentNetwork>::PARALLEL_OUTPUTS_THRESHOLD;
+        let outputs = (0..num_outputs)
+            .map(|_| Value::from_str(&format!("{}field", Field::<CurrentNetwork>::rand(rng))).unwrap())
+            .collect::<Vec<_>>();
+        let output_types = (0..num_outputs)
+            .map(|index| {
+                let plaintext_type = PlaintextType::Literal(LiteralType::Field);
+                match index % 3 {
+                    0 => ValueType::Constant(plaintext_type),
+                    1 => ValueType::Public(plaintext_type),
+                    _ => ValueType::Private(plaintext_type),
+                }
+            })
+            .collect::<Vec<_>>();
+        let output_operands = vec![None; num_outputs];
+
+        // Ensure the sequential and parallel paths produce identical responses.
+        let new_response = |parallel| {
+            Response::new_internal(
+                &network_id,
+                &program_id,
+                &function_name,
+                2,
+                &tvk,
+                &tcm,
+                outputs.clone(),
+                &output_types,
+                &output_operands,
+                parallel,
+            )
+            .unwrap()
+        };
+        let sequential = new_response(false);
+        let parallel = new_response(true);
+        assert_eq!(sequential, parallel);
+        assert_eq!(sequential.output_ids().len(), num_outputs);
+
+        // Ensure the public constructor matches as well.
+        let response = Response::new(
+            &network_id,
+            &program_id,
+            &function_name,
+            2,
+            &tvk,
+            &tcm,
+            outputs.clone(),
+            &output_types,
+            &output_operands,
+        )
+        .unwrap();
+        assert_eq!(response, sequential);
+    }
+
+    #[test]
+    fn test_response_rejects_mismatched_output_lengths() {
+        let rng = &mut TestRng::default();
+
+        // Sample the response components.
+        let network_id = U16::new(CurrentNetwork::ID);
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let function_name = Identifier::from_str("transfer").unwrap();
+        let tvk = Field::rand(rng);
+        let tcm = Field::rand(rng);
+        let output_type = ValueType::Public(PlaintextType::Literal(LiteralType::Field));
+        let output = Value::from_str("1field").unwrap();
+
+        let new_response = |outputs: Vec<Value<CurrentNetwork>>, num_types: usize| {
+            Response::new(
+                &network_id,
+                &program_id,
+                &function_name,
+                1,
+                &tvk,
+                &tcm,
+                outputs,
+                &vec![output_type.clone(); num_types],
+                &vec![None; num_types],
+            )
+        };
+
+        // Ensure matching lengths are accepted.
+        assert!(new_response(vec![output.clone(); 2], 2).is_ok());
+
+        // Ensure fewer outputs than output types are rejected.
+        let error = new_response(vec![output.clone()], 2).unwrap_err();
+        assert_eq!(error.to_string(), "Expected 2 outputs, found 1 outputs and 2 output registers");
+
+        // Ensure more outputs than output types are rejected.
+        let error = new_response(vec![output; 3], 2).unwrap_err();
+        assert_eq!(error.to_string(), "Expected 2 outputs, found 3 outputs and 2 output registers");
+    }
+
+    #[test]
+    fn test_record_owner_must_be_an_address() {
+        let rng = &mut TestRng::default();
+
+        // Sample the response components.
+        let network_id = U16::new(CurrentNetwork::ID);
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let function_name = Identifier::from_str("mint").unwrap();
+        let tvk = Field::rand(rng);
+        let tcm = Field::rand(rng);
+        let output_types = [ValueType::Record(Identifier::from_str("token").unwrap())];
+        let output_registers = [Some(Register::Locator(2))];
+
+        // Sample a record with a valid owner, and the nonce for the output register.
+        let randomizer = CurrentNetwork::hash_to_scalar_psd2(&[tvk, Field::from_u64(2)]).unwrap();
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, amount: 5u64.private, _nonce: {nonce}.public }}"
+        ))
+        .unwrap();
+        // Construct the same record, with an owner that is not an address.
+        let malformed = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_plaintext(
+            Owner::Private(Plaintext::from(Literal::U64(U64::new(5)))),
+            IndexMap::from_iter([(
+                Identifier::from_str("amount").unwrap(),
+                Entry::Private(Plaintext::from_str("5u64").unwrap()),
+            )]),
+            *record.nonce(),
+        )
+        .unwrap();
+
+        // Ensure the record with a valid owner is accepted.
+        let new_response = |record| {
+            Response::new(
+                &network_id,
+                &program_id,
+                &function_name,
+                1,
+                &tvk,
+                &tcm,
+                vec![Value::Record(record)],
+                &output_types,
+                &output_registers,
+            )
+        };
+        assert!(new_response(record).is_ok());
+
+        // Ensure the record without a valid owner is rejected.
+        let error = new_response(malformed).unwrap_err();
+        assert_eq!(error.to_string(), "Record owner must be an address");
+    }
 }
@@ -20,7 +20,8 @@ extern crate criterion;
 use console::{
     account::*,
     network::{MainnetV0, Network},
-    program::{Plaintext, Record, Value},
+    program::{compute_function_id, Plaintext, Record, Value},
+    types::U16,
 };
 use ledger_block::Transition;
 use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
@@ -182,6 +183,24 @@ fn execute(c: &mut Criterion) {
         c.bench_function("Transaction::Execute(transfer_private) - verify", |b| {
             b.iter(|| vm.check_transaction(&transaction, None, rng).unwrap())
         });
+
+        // Bench the output verification of transfer_private.
+        let transition = transaction.transitions().next().unwrap();
+        let function_id =
+            compute_function_id(&U16::new(MainnetV0::ID), transition.program_id(), transition.function_name()).unwrap();
+        let num_inputs = transition.inputs().len();
+        c.bench_function("Transition::verify_outputs - transfer_private", |b| {
+            b.iter(|| assert!(transition.verify_outputs(function_id)))
+        });
+        c.bench_function("Output::verify - transfer_private", |b| {
+            b.iter(|| {
+                assert!(transition.outputs().iter().enumerate().all(|(index, output)| output.verify(
+                    function_id,
+                    transition.tcm(),
+                    num_inputs + index
+                )))
+            })
+        });
     }
 
     // Bench Transaction.write_le + VM.check_transaction methods for transactions above the maximum transaction size.
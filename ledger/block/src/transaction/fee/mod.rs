@@ -19,7 +19,7 @@ mod string;
 use crate::{Input, Output, Transition};
 use console::{
     network::prelude::*,
-    program::{Argument, Literal, Plaintext},
+    program::{Argument, Ciphertext, Literal, Plaintext, Record},
     types::{Address, Field, U64},
 };
 use synthesizer_snark::Proof;
@@ -86,6 +86,15 @@ impl<N: Network> Fee<N> {
         }
     }
 
+    /// Returns the commitment and (encrypted) change record, if the fee is private.
+    #[allow(clippy::type_complexity)]
+    pub fn change_record(&self) -> Option<(&Field<N>, &Record<N, Ciphertext<N>>)> {
+        match self.is_fee_private() {
+            true => self.transition.outputs().last().and_then(Output::record),
+            false => None,
+        }
+    }
+
     /// Returns the amount (in microcredits).
     pub fn amount(&self) -> Result<U64<N>> {
         // Retrieve the base fee amount.
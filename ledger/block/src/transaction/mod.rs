@@ -29,8 +29,17 @@ mod string;
 use crate::Transition;
 use console::{
     network::prelude::*,
-    program::{Ciphertext, ProgramOwner, Record, TransactionLeaf, TransactionPath, TransactionTree, TRANSACTION_DEPTH},
-    types::{Field, Group, U64},
+    program::{
+        compute_function_id,
+        Ciphertext,
+        ProgramOwner,
+        Record,
+        TransactionLeaf,
+        TransactionPath,
+        TransactionTree,
+        TRANSACTION_DEPTH,
+    },
+    types::{Field, Group, U16, U64},
 };
 
 #[derive(Clone, PartialEq, Eq)]
@@ -77,6 +86,27 @@ impl<N: Network> Transaction<N> {
         // Construct the execution transaction.
         Ok(Self::Fee(id.into(), fee))
     }
+
+    /// Initializes a new fee transaction, after ensuring the fee transition is well-formed.
+    /// Note: This is used wherever a fee is converted into a fee transaction (e.g. for a rejected transaction),
+    /// and is not used when deserializing a fee transaction, which is checked in `VM::check_transaction`.
+    pub fn from_fee_checked(fee: Fee<N>) -> Result<Self> {
+        // Ensure the fee is for a fee transition.
+        ensure!(
+            fee.is_fee_private() || fee.is_fee_public(),
+            "Attempted to create a fee transaction with a non-fee transition"
+        );
+        // Ensure the fee outputs are well-formed.
+        let function_id = compute_function_id(&U16::new(N::ID), fee.program_id(), fee.function_name())?;
+        ensure!(fee.verify_outputs(function_id), "Attempted to create a fee transaction with malformed outputs");
+        // Ensure the fee contains a global state root for inclusion.
+        ensure!(
+            fee.global_state_root() != N::StateRoot::default(),
+            "Attempted to create a fee transaction without a global state root"
+        );
+        // Construct the fee transaction.
+        Self::from_fee(fee)
+    }
 }
 
 impl<N: Network> Transaction<N> {
@@ -469,3 +499,70 @@ pub mod test_helpers {
         Transaction::from_fee(fee).unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Output;
+    use console::{
+        network::MainnetV0,
+        program::{Future, Identifier, Literal, Plaintext, ProgramID},
+    };
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_from_fee_checked_rejects_tampered_fee() {
+        let rng = &mut TestRng::default();
+
+        // Construct the locator and transition commitment of a public fee.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("credits.aleo").unwrap();
+        let function_name = Identifier::from_str("fee_public").unwrap();
+        let function_id = compute_function_id(&U16::new(CurrentNetwork::ID), &program_id, &function_name).unwrap();
+        let tcm = Uniform::rand(rng);
+
+        // Construct the inputs, as the base fee, priority fee, and execution ID.
+        let inputs = vec![
+            crate::Input::Public(Uniform::rand(rng), Some(Plaintext::from(Literal::U64(U64::new(100))))),
+            crate::Input::Public(Uniform::rand(rng), Some(Plaintext::from(Literal::U64(U64::new(0))))),
+            crate::Input::Public(Uniform::rand(rng), Some(Plaintext::from(Literal::Field(Uniform::rand(rng))))),
+        ];
+        // Construct the future output at index 3.
+        let future = Future::new(program_id, function_name, vec![]);
+        let mut preimage = vec![function_id];
+        preimage.extend(future.to_fields().unwrap());
+        preimage.extend([tcm, Field::from_u16(3)]);
+        let output_id = CurrentNetwork::hash_psd8(&preimage).unwrap();
+
+        // Construct a fee for the given output ID and global state root.
+        let sample_fee = |output_id, global_state_root, rng: &mut TestRng| {
+            let output = Output::Future(output_id, Some(future.clone()));
+            let transition = Transition::new(
+                program_id,
+                function_name,
+                inputs.clone(),
+                vec![output],
+                Uniform::rand(rng),
+                tcm,
+                Uniform::rand(rng),
+            )
+            .unwrap();
+            Fee::from_unchecked(transition, global_state_root, None)
+        };
+
+        // Ensure a well-formed fee is accepted.
+        let global_state_root = Uniform::rand(rng);
+        assert!(Transaction::from_fee_checked(sample_fee(output_id, global_state_root, rng)).is_ok());
+
+        // Ensure a fee with a tampered output is rejected.
+        let tampered = sample_fee(Uniform::rand(rng), global_state_root, rng);
+        let error = Transaction::from_fee_checked(tampered.clone()).unwrap_err();
+        assert_eq!(error.to_string(), "Attempted to create a fee transaction with malformed outputs");
+        // Ensure the unchecked constructor is unchanged.
+        assert!(Transaction::from_fee(tampered).is_ok());
+
+        // Ensure a fee without a global state root is rejected.
+        let error = Transaction::from_fee_checked(sample_fee(output_id, Default::default(), rng)).unwrap_err();
+        assert_eq!(error.to_string(), "Attempted to create a fee transaction without a global state root");
+    }
+}
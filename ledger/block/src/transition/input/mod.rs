@@ -99,6 +99,14 @@ impl<N: Network> Input<N> {
         }
     }
 
+    /// Returns the plaintext, if the input is public and the plaintext exists.
+    pub const fn public_plaintext(&self) -> Option<&Plaintext<N>> {
+        match self {
+            Input::Public(_, Some(plaintext)) => Some(plaintext),
+            _ => None,
+        }
+    }
+
     /// Returns the public verifier inputs for the proof.
     pub fn verifier_inputs(&self) -> impl '_ + Iterator<Item = N::Field> {
         [Some(self.id()), self.tag()].into_iter().flatten().map(|id| **id)
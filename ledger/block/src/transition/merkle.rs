@@ -94,6 +94,14 @@ impl<N: Network> Transition<N> {
     }
 }
 
+/// Returns the root of a Merkle tree over the IDs of the given outputs.
+/// Note: The leaves are the output leaves of the transition tree, indexed as if the transition has
+/// no inputs. Hence, for a transition without inputs, this root matches `Transition::to_root`,
+/// which is the commitment to the outputs produced in `Process::execute`.
+pub fn output_set_root<N: Network>(outputs: &[Output<N>]) -> Result<Field<N>> {
+    Ok(*Transition::function_tree(&[], outputs)?.root())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
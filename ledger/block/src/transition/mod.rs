@@ -16,12 +16,18 @@ pub mod input;
 pub use input::Input;
 
 pub mod output;
-pub use output::Output;
+pub use output::{diff_outputs, Output, OutputVerifyReport, OutputView, PublicInputs, SpendableRecord};
 
 mod bytes;
 mod merkle;
 mod serialize;
 mod string;
+mod summary;
+mod verify;
+
+pub use merkle::output_set_root;
+pub use summary::TransitionSummary;
+pub use verify::verify_block_outputs;
 
 use console::{
     network::prelude::*,
@@ -31,6 +37,7 @@ use console::{
         Identifier,
         InputID,
         OutputID,
+        Plaintext,
         ProgramID,
         Record,
         Register,
@@ -91,6 +98,18 @@ impl<N: Network> Transition<N> {
         response: &Response<N>,
         output_types: &[ValueType<N>],
         output_registers: &[Option<Register<N>>],
+    ) -> Result<Self> {
+        Self::from_streaming(request, response, output_types, output_registers, |_| Ok(()))
+    }
+
+    /// Initializes a new transition from a request and response, and calls `on_output` on each transition output,
+    /// in order, as soon as the output is constructed and checked against its output ID (and checksum).
+    pub fn from_streaming(
+        request: &Request<N>,
+        response: &Response<N>,
+        output_types: &[ValueType<N>],
+        output_registers: &[Option<Register<N>>],
+        mut on_output: impl FnMut(&Output<N>) -> Result<()>,
     ) -> Result<Self> {
         let network_id = *request.network_id();
         let program_id = *request.program_id();
@@ -100,54 +119,26 @@ impl<N: Network> Transition<N> {
         // Compute the function ID.
         let function_id = compute_function_id(&network_id, &program_id, &function_name)?;
 
-        let inputs = request
-            .input_ids()
-            .iter()
-            .zip_eq(request.inputs())
-            .enumerate()
-            .map(|(index, (input_id, input))| {
-                // Construct the transition input.
-                match (input_id, input) {
-                    (InputID::Constant(input_hash), Value::Plaintext(plaintext)) => {
-                        // Construct the constant input.
-                        let input = Input::Constant(*input_hash, Some(plaintext.clone()));
-                        // Ensure the input is valid.
-                        match input.verify(function_id, request.tcm(), index) {
-                            true => Ok(input),
-                            false => bail!("Malformed constant transition input: '{input}'"),
-                        }
-                    }
-                    (InputID::Public(input_hash), Value::Plaintext(plaintext)) => {
-                        // Construct the public input.
-                        let input = Input::Public(*input_hash, Some(plaintext.clone()));
-                        // Ensure the input is valid.
-                        match input.verify(function_id, request.tcm(), index) {
-                            true => Ok(input),
-                            false => bail!("Malformed public transition input: '{input}'"),
-                        }
-                    }
-                    (InputID::Private(input_hash), Value::Plaintext(plaintext)) => {
-                        // Construct the (console) input index as a field element.
-                        let index = Field::from_u16(index as u16);
-                        // Compute the ciphertext, with the input view key as `Hash(function ID || tvk || index)`.
-                        let ciphertext =
-                            plaintext.encrypt_symmetric(N::hash_psd4(&[function_id, *request.tvk(), index])?)?;
-                        // Compute the ciphertext hash.
-                        let ciphertext_hash = N::hash_psd8(&ciphertext.to_fields()?)?;
-                        // Ensure the ciphertext hash matches.
-                        ensure!(*input_hash == ciphertext_hash, "The input ciphertext hash is incorrect");
-                        // Return the private input.
-                        Ok(Input::Private(*input_hash, Some(ciphertext)))
-                    }
-                    (InputID::Record(_, _, serial_number, tag), Value::Record(..)) => {
-                        // Return the input record.
-                        Ok(Input::Record(*serial_number, *tag))
-                    }
-                    (InputID::ExternalRecord(input_hash), Value::Record(..)) => Ok(Input::ExternalRecord(*input_hash)),
-                    _ => bail!("Malformed request input: {:?}, {input}", input_id),
-                }
-            })
-            .collect::<Result<Vec<_>>>()?;
+        // Construct the transition inputs.
+        let inputs = Self::inputs_from_request(request, function_id)?;
+
+        // Ensure the response does not contain more outputs than the function declares.
+        let num_outputs = output_types.len();
+        if response.outputs().len() > num_outputs {
+            // Report the index of the last output, which is out of bounds.
+            let index = response.outputs().len() - 1;
+            bail!("Output index {index} is out of bounds, as the function declares {num_outputs} outputs")
+        }
+        // Ensure the number of outputs, output IDs, output types, and output registers match.
+        ensure!(
+            response.outputs().len() == num_outputs
+                && response.output_ids().len() == num_outputs
+                && output_registers.len() == num_outputs,
+            "Expected {num_outputs} outputs, found {} outputs, {} output IDs, and {} output registers",
+            response.outputs().len(),
+            response.output_ids().len(),
+            output_registers.len()
+        );
 
         let outputs = response
             .output_ids()
@@ -252,6 +243,12 @@ impl<N: Network> Transition<N> {
                     _ => bail!("Malformed response output: {output_id:?}, {output}"),
                 }
             })
+            .map(|output| {
+                // Emit the output.
+                let output = output?;
+                on_output(&output)?;
+                Ok(output)
+            })
             .collect::<Result<Vec<_>>>()?;
 
         // Retrieve the `tpk`.
@@ -263,6 +260,90 @@ impl<N: Network> Transition<N> {
         // Return the transition.
         Self::new(program_id, function_name, inputs, outputs, tpk, tcm, scm)
     }
+
+    /// Initializes a new transition from a request and the computed transition outputs.
+    ///
+    /// Note: Each output must contain its value, and is checked to match the request.
+    pub fn from_outputs(request: &Request<N>, outputs: Vec<Output<N>>) -> Result<Self> {
+        let num_inputs = request.inputs().len();
+
+        // Compute the function ID.
+        let function_id = compute_function_id(request.network_id(), request.program_id(), request.function_name())?;
+
+        // Construct the transition inputs.
+        let inputs = Self::inputs_from_request(request, function_id)?;
+
+        // Ensure the outputs are well-formed.
+        for (index, output) in outputs.iter().enumerate() {
+            ensure!(
+                output.verify(function_id, request.tcm(), num_inputs + index),
+                "Malformed transition output at index {index}: '{output}'"
+            );
+        }
+
+        // Return the transition.
+        Self::new(
+            *request.program_id(),
+            *request.function_name(),
+            inputs,
+            outputs,
+            request.to_tpk(),
+            *request.tcm(),
+            *request.scm(),
+        )
+    }
+
+    /// Returns the transition inputs for the given request.
+    fn inputs_from_request(request: &Request<N>, function_id: Field<N>) -> Result<Vec<Input<N>>> {
+        request
+            .input_ids()
+            .iter()
+            .zip_eq(request.inputs())
+            .enumerate()
+            .map(|(index, (input_id, input))| {
+                // Construct the transition input.
+                match (input_id, input) {
+                    (InputID::Constant(input_hash), Value::Plaintext(plaintext)) => {
+                        // Construct the constant input.
+                        let input = Input::Constant(*input_hash, Some(plaintext.clone()));
+                        // Ensure the input is valid.
+                        match input.verify(function_id, request.tcm(), index) {
+                            true => Ok(input),
+                            false => bail!("Malformed constant transition input: '{input}'"),
+                        }
+                    }
+                    (InputID::Public(input_hash), Value::Plaintext(plaintext)) => {
+                        // Construct the public input.
+                        let input = Input::Public(*input_hash, Some(plaintext.clone()));
+                        // Ensure the input is valid.
+                        match input.verify(function_id, request.tcm(), index) {
+                            true => Ok(input),
+                            false => bail!("Malformed public transition input: '{input}'"),
+                        }
+                    }
+                    (InputID::Private(input_hash), Value::Plaintext(plaintext)) => {
+                        // Construct the (console) input index as a field element.
+                        let index = Field::from_u16(index as u16);
+                        // Compute the ciphertext, with the input view key as `Hash(function ID || tvk || index)`.
+                        let ciphertext =
+                            plaintext.encrypt_symmetric(N::hash_psd4(&[function_id, *request.tvk(), index])?)?;
+                        // Compute the ciphertext hash.
+                        let ciphertext_hash = N::hash_psd8(&ciphertext.to_fields()?)?;
+                        // Ensure the ciphertext hash matches.
+                        ensure!(*input_hash == ciphertext_hash, "The input ciphertext hash is incorrect");
+                        // Return the private input.
+                        Ok(Input::Private(*input_hash, Some(ciphertext)))
+                    }
+                    (InputID::Record(_, _, serial_number, tag), Value::Record(..)) => {
+                        // Return the input record.
+                        Ok(Input::Record(*serial_number, *tag))
+                    }
+                    (InputID::ExternalRecord(input_hash), Value::Record(..)) => Ok(Input::ExternalRecord(*input_hash)),
+                    _ => bail!("Malformed request input: {:?}, {input}", input_id),
+                }
+            })
+            .collect::<Result<Vec<_>>>()
+    }
 }
 
 impl<N: Network> Transition<N> {
@@ -421,6 +502,11 @@ impl<N: Network> Transition<N> {
         self.inputs.iter().flat_map(Input::tag)
     }
 
+    /// Returns an iterator over the revealed plaintexts, for inputs that are public.
+    pub fn public_inputs(&self) -> impl '_ + Iterator<Item = &Plaintext<N>> {
+        self.inputs.iter().flat_map(Input::public_plaintext)
+    }
+
     /* Output */
 
     /// Returns the output IDs.
@@ -442,6 +528,19 @@ impl<N: Network> Transition<N> {
     pub fn records(&self) -> impl '_ + Iterator<Item = (&Field<N>, &Record<N, Ciphertext<N>>)> {
         self.outputs.iter().flat_map(Output::record)
     }
+
+    /// Returns `true` if all of the outputs are well-formed, for the given function ID.
+    /// Note: Outputs with a missing value are rejected before any output is hashed.
+    pub fn verify_outputs(&self, function_id: Field<N>) -> bool {
+        // Ensure none of the outputs are missing a value.
+        if self.outputs.iter().any(Output::is_value_missing) {
+            return false;
+        }
+        // Ensure each output is well-formed, i.e. each output with a value hashes to its output ID,
+        // and each external record has a non-zero ID.
+        let num_inputs = self.inputs.len();
+        self.outputs.iter().enumerate().all(|(index, output)| output.verify(function_id, &self.tcm, num_inputs + index))
+    }
 }
 
 impl<N: Network> Transition<N> {
@@ -145,9 +145,36 @@ impl<N: Network> ToBytes for Output<N> {
     }
 }
 
+impl<N: Network> Output<N> {
+    /// Reads the output from a buffer, and ensures the value (if present) hashes to the ID (or checksum) of the output.
+    /// This catches a corrupt output at parse time, and is intended for untrusted input.
+    /// Note: For trusted storage, use `Output::from_bytes_le`, which does not recompute the hash.
+    pub fn from_bytes_le_checked(bytes: &[u8], function_id: Field<N>, tcm: &Field<N>, index: usize) -> Result<Self> {
+        // Read the output.
+        let output = Self::from_bytes_le(bytes)?;
+        // If the output contains a value, ensure the value hashes to the output ID (or checksum).
+        match output {
+            Self::Constant(_, None)
+            | Self::Public(_, None)
+            | Self::Private(_, None)
+            | Self::Record(_, _, None)
+            | Self::ExternalRecord(..)
+            | Self::Future(_, None) => (),
+            _ => {
+                let report = output.explain_verify(function_id, tcm, index)?;
+                ensure!(report.is_valid(), "{report}");
+            }
+        }
+        Ok(output)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
 
     #[test]
     fn test_bytes() {
@@ -157,4 +184,47 @@ mod tests {
             assert_eq!(expected, Output::read_le(&expected_bytes[..]).unwrap());
         }
     }
+
+    #[test]
+    fn test_from_bytes_le_checked() {
+        let rng = &mut TestRng::default();
+
+        // Sample the function ID and transition commitment.
+        let function_id = Field::<CurrentNetwork>::rand(rng);
+        let tcm = Field::<CurrentNetwork>::rand(rng);
+        let index = 2;
+
+        // Construct a public output, with its ID computed as `Hash(function ID || output || tcm || index)`.
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("42u64").unwrap();
+        let mut preimage = vec![function_id];
+        preimage.extend(plaintext.to_fields().unwrap());
+        preimage.extend([tcm, Field::from_u16(index as u16)]);
+        let id = CurrentNetwork::hash_psd8(&preimage).unwrap();
+
+        // Ensure a well-formed output is accepted.
+        let expected = Output::Public(id, Some(plaintext));
+        let bytes = expected.to_bytes_le().unwrap();
+        assert_eq!(expected, Output::from_bytes_le_checked(&bytes, function_id, &tcm, index).unwrap());
+        // Ensure the output is rejected in a different context.
+        assert!(Output::from_bytes_le_checked(&bytes, function_id, &tcm, index + 1).is_err());
+
+        // Ensure an output whose value does not match its ID is rejected, but is accepted by the unchecked path.
+        let tampered = Output::Public(id, Some(Plaintext::from_str("43u64").unwrap())).to_bytes_le().unwrap();
+        let error = Output::from_bytes_le_checked(&tampered, function_id, &tcm, index).unwrap_err();
+        assert!(error.to_string().contains("Output id mismatch"), "{error}");
+        assert!(Output::<CurrentNetwork>::from_bytes_le(&tampered).is_ok());
+
+        // Ensure a record output is checked against its checksum.
+        let outputs = crate::transition::output::test_helpers::sample_outputs();
+        let Output::Record(commitment, checksum, Some(record)) = outputs[10].1.clone() else { unreachable!() };
+        let bytes = Output::Record(commitment, checksum, Some(record.clone())).to_bytes_le().unwrap();
+        assert!(Output::from_bytes_le_checked(&bytes, function_id, &tcm, index).is_ok());
+        let tampered = Output::Record(commitment, Field::rand(rng), Some(record)).to_bytes_le().unwrap();
+        let error = Output::from_bytes_le_checked(&tampered, function_id, &tcm, index).unwrap_err();
+        assert!(error.to_string().contains("Output checksum mismatch"), "{error}");
+
+        // Ensure an output without a value is accepted.
+        let bytes = Output::<CurrentNetwork>::Public(id, None).to_bytes_le().unwrap();
+        assert!(Output::from_bytes_le_checked(&bytes, function_id, &tcm, index).is_ok());
+    }
 }
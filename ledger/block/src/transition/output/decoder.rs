@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use core::marker::PhantomData;
+use std::io::ErrorKind;
+
+/// An incremental decoder of outputs, which buffers bytes until a complete output is available.
+#[derive(Clone, Debug)]
+pub struct OutputDecoder<N: Network> {
+    /// The buffered bytes of the next output.
+    buffer: Vec<u8>,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network> Default for OutputDecoder<N> {
+    /// Initializes a new output decoder.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> OutputDecoder<N> {
+    /// Initializes a new output decoder.
+    pub fn new() -> Self {
+        Self { buffer: Vec::new(), _phantom: PhantomData }
+    }
+
+    /// Returns the number of buffered bytes.
+    pub fn num_buffered_bytes(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Appends the given bytes to the buffer, and returns the next output, if it is complete.
+    /// Note: At most one output is returned per call, and any remaining bytes are kept for the next output.
+    /// If the buffered bytes are not a valid output, the buffer is cleared and an error is returned.
+    pub fn feed(&mut self, bytes: &[u8]) -> Result<Option<Output<N>>> {
+        self.buffer.extend_from_slice(bytes);
+
+        // Attempt to read an output from the buffered bytes.
+        let mut reader = &self.buffer[..];
+        match Output::read_le(&mut reader) {
+            Ok(output) => {
+                // Remove the bytes of the output from the buffer.
+                let num_read = self.buffer.len() - reader.len();
+                self.buffer.drain(..num_read);
+                Ok(Some(output))
+            }
+            // If the buffered bytes end before the output does, wait for more bytes.
+            Err(error) if error.kind() == ErrorKind::UnexpectedEof => Ok(None),
+            Err(error) => {
+                self.buffer.clear();
+                bail!("Failed to decode the buffered output: {error}")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_output_decoder() {
+        for (_, expected) in crate::transition::output::test_helpers::sample_outputs() {
+            let bytes = expected.to_bytes_le().unwrap();
+
+            // Ensure the output is decoded when fed one byte at a time.
+            let mut decoder = OutputDecoder::<CurrentNetwork>::new();
+            for byte in &bytes[..bytes.len() - 1] {
+                assert!(decoder.feed(&[*byte]).unwrap().is_none());
+            }
+            assert_eq!(decoder.feed(&bytes[bytes.len() - 1..]).unwrap(), Some(expected.clone()));
+            assert_eq!(decoder.num_buffered_bytes(), 0);
+
+            // Ensure consecutive outputs fed in a single chunk are decoded in order.
+            let chunk = [bytes.clone(), bytes.clone()].concat();
+            assert_eq!(decoder.feed(&chunk).unwrap(), Some(expected.clone()));
+            assert_eq!(decoder.feed(&[]).unwrap(), Some(expected));
+            assert_eq!(decoder.num_buffered_bytes(), 0);
+        }
+
+        // Ensure an invalid variant is rejected.
+        let mut decoder = OutputDecoder::<CurrentNetwork>::new();
+        assert!(decoder.feed(&[6]).is_err());
+        assert_eq!(decoder.num_buffered_bytes(), 0);
+    }
+}
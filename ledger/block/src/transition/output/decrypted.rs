@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        RwLock,
+    },
+};
+
+/// A record output that memoizes its decrypted record, keyed by the view key.
+/// Note: The output is not modified, the decrypted records are cached alongside it.
+pub struct DecryptedOutput<N: Network> {
+    /// The record output.
+    output: Output<N>,
+    /// The mapping of view keys to decrypted records.
+    cache: RwLock<HashMap<ViewKey<N>, Record<N, Plaintext<N>>>>,
+    /// The number of times the record was decrypted.
+    num_decryptions: AtomicUsize,
+}
+
+impl<N: Network> DecryptedOutput<N> {
+    /// Initializes a new decrypted output, from a record output with a record ciphertext.
+    pub fn new(output: Output<N>) -> Result<Self> {
+        // Ensure the output is a record output with a record ciphertext.
+        ensure!(output.record().is_some(), "Expected a record output with a record ciphertext");
+        // Return the decrypted output.
+        Ok(Self { output, cache: Default::default(), num_decryptions: AtomicUsize::new(0) })
+    }
+
+    /// Returns the record output.
+    pub const fn output(&self) -> &Output<N> {
+        &self.output
+    }
+
+    /// Returns the number of times the record was decrypted, excluding the reads served from the cache.
+    pub fn num_decryptions(&self) -> usize {
+        self.num_decryptions.load(Ordering::Relaxed)
+    }
+
+    /// Returns the decrypted record for the given view key, decrypting the record only if it is not cached.
+    pub fn decrypt(&self, view_key: &ViewKey<N>) -> Result<Record<N, Plaintext<N>>> {
+        // If the record is cached for the view key, return it.
+        let cache = self.cache.read().map_err(|_| anyhow!("The decryption cache is poisoned"))?;
+        if let Some(record) = cache.get(view_key) {
+            return Ok(record.clone());
+        }
+        drop(cache);
+
+        // Retrieve the record ciphertext.
+        let Some((_, ciphertext)) = self.output.record() else {
+            bail!("Expected a record output with a record ciphertext")
+        };
+        // Decrypt the record.
+        let record = ciphertext.decrypt(view_key)?;
+        // Increment the number of decryptions.
+        self.num_decryptions.fetch_add(1, Ordering::Relaxed);
+
+        // Cache the record for the view key.
+        self.cache.write().map_err(|_| anyhow!("The decryption cache is poisoned"))?.insert(*view_key, record.clone());
+        Ok(record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::MainnetV0, types::Address};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_decrypt_is_cached() {
+        let rng = &mut TestRng::default();
+
+        // Sample an account.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let owner = Address::try_from(&private_key).unwrap();
+
+        // Sample a record output.
+        let randomizer = Uniform::rand(rng);
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {owner}.private, token_amount: 100u64.private, _nonce: {nonce}.public }}"
+        ))
+        .unwrap();
+        let ciphertext = record.encrypt(randomizer).unwrap();
+        let checksum = CurrentNetwork::hash_bhp1024(&ciphertext.to_bits_le()).unwrap();
+        let output = DecryptedOutput::new(Output::Record(Uniform::rand(rng), checksum, Some(ciphertext))).unwrap();
+        assert_eq!(output.num_decryptions(), 0);
+
+        // Ensure the first decryption decrypts the record.
+        assert_eq!(output.decrypt(&view_key).unwrap(), record);
+        assert_eq!(output.num_decryptions(), 1);
+
+        // Ensure the second decryption is served from the cache.
+        assert_eq!(output.decrypt(&view_key).unwrap(), record);
+        assert_eq!(output.num_decryptions(), 1);
+
+        // Ensure a non-record output is rejected.
+        assert!(DecryptedOutput::new(Output::<CurrentNetwork>::Public(Uniform::rand(rng), None)).is_err());
+    }
+}
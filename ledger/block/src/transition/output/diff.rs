@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use indexmap::IndexSet;
+
+/// Returns the IDs of the remote outputs that are missing locally, and the IDs of the local outputs
+/// that are missing remotely, in the order they appear in `remote` and `local` respectively.
+/// Note: Outputs are matched on their committed content (the variant, ID, and checksum) as in `Output::content_id`,
+/// so an output matches with or without its payload, and equal IDs of different variants do not match.
+pub fn diff_outputs<N: Network>(local: &[Output<N>], remote: &[Output<N>]) -> (Vec<Field<N>>, Vec<Field<N>>) {
+    // Returns the committed content of the output.
+    let content = |output: &Output<N>| (output.variant(), *output.id(), output.checksum().copied());
+
+    // Collect the committed content of the local and remote outputs.
+    let local_set = local.iter().map(content).collect::<IndexSet<_>>();
+    let remote_set = remote.iter().map(content).collect::<IndexSet<_>>();
+
+    // Compute the outputs missing locally, and the outputs missing remotely.
+    let missing = remote_set.difference(&local_set).map(|(_, id, _)| *id).collect();
+    let extra = local_set.difference(&remote_set).map(|(_, id, _)| *id).collect();
+    (missing, extra)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_diff_outputs() {
+        let rng = &mut TestRng::default();
+
+        // Sample the outputs.
+        let [a, b, c, d] = [(); 4].map(|_| Output::<CurrentNetwork>::Public(Uniform::rand(rng), None));
+
+        // Ensure overlapping sets return the outputs unique to each side.
+        let local = [a.clone(), b.clone(), c.clone()];
+        let remote = [b.clone(), c.clone(), d.clone()];
+        assert_eq!(diff_outputs(&local, &remote), (vec![*d.id()], vec![*a.id()]));
+
+        // Ensure disjoint sets return all of the outputs.
+        let local = [a.clone(), b.clone()];
+        let remote = [c.clone(), d.clone()];
+        assert_eq!(diff_outputs(&local, &remote), (vec![*c.id(), *d.id()], vec![*a.id(), *b.id()]));
+
+        // Ensure equal sets (with and without payloads) return no outputs.
+        let sample = crate::transition::output::test_helpers::sample_outputs();
+        let local = sample.iter().map(|(_, output)| output.clone()).collect::<Vec<_>>();
+        let remote = local
+            .iter()
+            .map(|output| match *output {
+                Output::Constant(id, _) => Output::Constant(id, None),
+                Output::Public(id, _) => Output::Public(id, None),
+                Output::Private(id, _) => Output::Private(id, None),
+                Output::Record(commitment, checksum, _) => Output::Record(commitment, checksum, None),
+                Output::ExternalRecord(id) => Output::ExternalRecord(id),
+                Output::Future(id, _) => Output::Future(id, None),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(diff_outputs(&local, &remote), (vec![], vec![]));
+
+        // Ensure an equal ID of a different variant does not match.
+        let id = *a.id();
+        assert_eq!(diff_outputs(&[a], &[Output::Private(id, None)]), (vec![id], vec![id]));
+    }
+}
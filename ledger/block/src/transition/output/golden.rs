@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// Returns a fixed set of outputs (one or more per variant), each with its canonical hex serialization,
+/// so that the byte encoding of an output can be checked to remain stable.
+/// Note: The IDs are small constants rather than the hashes of the values, so the outputs do not pass `verify`.
+#[rustfmt::skip]
+pub fn golden_outputs<N: Network>() -> Vec<(Output<N>, &'static str)> {
+    // Returns the field element for the given integer.
+    let field = |value: u64| Field::<N>::from_u64(value);
+    // Returns the plaintext for the given literal.
+    let literal = |string: &str| Plaintext::<N>::from_str(string).unwrap();
+
+    vec![
+        (
+            Output::Constant(field(1), None),
+            concat!(
+                "00",
+                "0100000000000000000000000000000000000000000000000000000000000000",
+                "00",
+            ),
+        ),
+        (
+            Output::Constant(field(2), Some(literal("5u8"))),
+            concat!(
+                "00",
+                "0200000000000000000000000000000000000000000000000000000000000000",
+                "01",
+                "00",
+                "0900",
+                "05",
+            ),
+        ),
+        (
+            Output::Public(field(3), None),
+            concat!(
+                "01",
+                "0300000000000000000000000000000000000000000000000000000000000000",
+                "00",
+            ),
+        ),
+        (
+            Output::Public(field(4), Some(literal("7u64"))),
+            concat!(
+                "01",
+                "0400000000000000000000000000000000000000000000000000000000000000",
+                "01",
+                "00",
+                "0c00",
+                "0700000000000000",
+            ),
+        ),
+        (
+            Output::Public(field(5), Some(literal("10field"))),
+            concat!(
+                "01",
+                "0500000000000000000000000000000000000000000000000000000000000000",
+                "01",
+                "00",
+                "0200",
+                "0a00000000000000000000000000000000000000000000000000000000000000",
+            ),
+        ),
+        (
+            Output::Private(field(6), None),
+            concat!(
+                "02",
+                "0600000000000000000000000000000000000000000000000000000000000000",
+                "00",
+            ),
+        ),
+        (
+            Output::Record(field(7), field(8), None),
+            concat!(
+                "03",
+                "0700000000000000000000000000000000000000000000000000000000000000",
+                "0800000000000000000000000000000000000000000000000000000000000000",
+                "00",
+            ),
+        ),
+        (
+            Output::ExternalRecord(field(9)),
+            concat!(
+                "04",
+                "0900000000000000000000000000000000000000000000000000000000000000",
+            ),
+        ),
+        (
+            Output::Future(field(10), None),
+            concat!(
+                "05",
+                "0a00000000000000000000000000000000000000000000000000000000000000",
+                "00",
+            ),
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_golden_outputs() {
+        for (expected, golden) in golden_outputs::<CurrentNetwork>() {
+            // Ensure the output serializes to the golden hex.
+            let bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(bytes.iter().map(|byte| format!("{byte:02x}")).collect::<String>(), golden, "{expected}");
+            // Ensure the output deserializes from the golden bytes.
+            assert_eq!(Output::read_le(&bytes[..]).unwrap(), expected);
+        }
+    }
+}
@@ -13,17 +13,58 @@
 // limitations under the License.
 
 mod bytes;
+mod decoder;
+mod decrypted;
+mod diff;
+#[cfg(any(test, feature = "test"))]
+mod golden;
+mod ownership;
+mod public_inputs;
+mod report;
 mod serialize;
+mod spendable;
+mod split;
 mod string;
+mod view;
+
+pub use decoder::*;
+pub use decrypted::*;
+pub use diff::*;
+#[cfg(any(test, feature = "test"))]
+pub use golden::*;
+pub use ownership::*;
+pub use public_inputs::*;
+pub use report::*;
+pub use spendable::*;
+pub use split::*;
+pub use view::*;
 
 use console::{
+    account::ViewKey,
     network::prelude::*,
-    program::{Ciphertext, Future, Plaintext, Record, TransitionLeaf},
+    program::{Ciphertext, Entry, Future, Identifier, Plaintext, ProgramID, Record, TransitionLeaf, Value},
     types::{Field, Group},
 };
 
+use indexmap::IndexMap;
+
 type Variant = u8;
 
+/// The variant of a constant output.
+const CONSTANT_VARIANT: Variant = 0;
+/// The variant of a public output.
+const PUBLIC_VARIANT: Variant = 1;
+/// The variant of a private output.
+const PRIVATE_VARIANT: Variant = 2;
+/// The variant of a record output.
+const RECORD_VARIANT: Variant = 3;
+/// The variant of an external record output.
+const EXTERNAL_RECORD_VARIANT: Variant = 4;
+/// The variant of a future output.
+const FUTURE_VARIANT: Variant = 5;
+/// The number of output variants.
+const NUM_VARIANTS: Variant = 6;
+
 /// The transition output.
 #[derive(Clone, PartialEq, Eq)]
 pub enum Output<N: Network> {
@@ -41,16 +82,46 @@ pub enum Output<N: Network> {
     Future(Field<N>, Option<Future<N>>),
 }
 
+/// The private view of a transition output, which is the ciphertext of a private output or a record output.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PrivateView<'a, N: Network> {
+    /// The ciphertext hash and ciphertext of a private output.
+    Private(&'a Field<N>, &'a Ciphertext<N>),
+    /// The commitment, checksum, and record ciphertext of a record output.
+    Record(&'a Field<N>, &'a Field<N>, &'a Record<N, Ciphertext<N>>),
+}
+
+impl<N: Network> core::hash::Hash for Output<N> {
+    /// Hashes the variant, ID, and checksum (if any) of the output.
+    /// Note: The optional payload of the output is not hashed, so an output hashes equally
+    /// with or without its payload, and equal outputs always hash equally.
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.variant().hash(state);
+        self.id().hash(state);
+        self.checksum().hash(state);
+    }
+}
+
 impl<N: Network> Output<N> {
     /// Returns the variant of the output.
     pub const fn variant(&self) -> Variant {
         match self {
-            Output::Constant(_, _) => 0,
-            Output::Public(_, _) => 1,
-            Output::Private(_, _) => 2,
-            Output::Record(_, _, _) => 3,
-            Output::ExternalRecord(_) => 4,
-            Output::Future(_, _) => 5,
+            Output::Constant(_, _) => CONSTANT_VARIANT,
+            Output::Public(_, _) => PUBLIC_VARIANT,
+            Output::Private(_, _) => PRIVATE_VARIANT,
+            Output::Record(_, _, _) => RECORD_VARIANT,
+            Output::ExternalRecord(_) => EXTERNAL_RECORD_VARIANT,
+            Output::Future(_, _) => FUTURE_VARIANT,
+        }
+    }
+
+    /// Returns the number of public verifier inputs for an output of the given variant,
+    /// or `None` if the variant is invalid. A record output also contributes its checksum.
+    pub const fn num_verifier_inputs(variant: Variant) -> Option<usize> {
+        match variant {
+            RECORD_VARIANT => Some(2),
+            variant if variant < NUM_VARIANTS => Some(1),
+            _ => None,
         }
     }
 
@@ -67,10 +138,30 @@ impl<N: Network> Output<N> {
     }
 
     /// Returns the output as a transition leaf.
+    /// Note: The leaf commits to the variant, which domain-separates equal output IDs across variants.
     pub fn to_transition_leaf(&self, index: u8) -> TransitionLeaf<N> {
         TransitionLeaf::new_with_version(index, self.variant(), *self.id())
     }
 
+    /// Returns a content ID that uniquely identifies the committed content of the output.
+    /// The content ID is computed as `Hash(variant || id || checksum)`, where the checksum is only present
+    /// for a record output. Unlike `id`, the content ID is distinct across variants for the same ID.
+    /// Note: The optional payload of the output is not hashed, as the ID and checksum commit to it.
+    pub fn content_id(&self) -> Result<Field<N>> {
+        // Construct the preimage as `(variant || id || checksum)`.
+        let mut preimage = vec![Field::from_u8(self.variant()), *self.id()];
+        preimage.extend(self.checksum().copied());
+        // Hash the preimage to a field element.
+        N::hash_psd4(&preimage)
+    }
+
+    /// Returns a stable byte key for the output in a key-value store, which is the little-endian encoding of the ID.
+    /// Note: A record output is keyed by its commitment, which matches the record map of the output store.
+    /// Double-spends are checked against the serial numbers of the inputs, not the commitments of the outputs.
+    pub fn storage_key(&self) -> Result<Vec<u8>> {
+        self.id().to_bytes_le()
+    }
+
     /// Returns the commitment and record, if the output is a record.
     #[allow(clippy::type_complexity)]
     pub const fn record(&self) -> Option<(&Field<N>, &Record<N, Ciphertext<N>>)> {
@@ -137,6 +228,30 @@ impl<N: Network> Output<N> {
         }
     }
 
+    /// Returns the private view of the output, if the output is a private output or a record output.
+    /// Note: Each variant keeps its own commitment scheme, as the ciphertext hash of a private output
+    /// and the commitment and checksum of a record output are not interchangeable.
+    pub fn as_private_view(&self) -> Result<PrivateView<'_, N>> {
+        match self {
+            Output::Private(id, Some(ciphertext)) => Ok(PrivateView::Private(id, ciphertext)),
+            Output::Record(commitment, checksum, Some(record)) => Ok(PrivateView::Record(commitment, checksum, record)),
+            _ => bail!("Expected a private or record output with a ciphertext"),
+        }
+    }
+
+    /// Decrypts the private output, given the function ID, the transition view key, and the index of the output
+    /// in the transition, which is the number of function inputs plus the position of the output.
+    /// Note: The ciphertext is encrypted under `Hash(function ID || tvk || index)`, one field element at a time,
+    /// so each member of a struct output is reconstructed from its own field elements.
+    pub fn decrypt_private(&self, function_id: Field<N>, tvk: Field<N>, index: u16) -> Result<Plaintext<N>> {
+        // Retrieve the ciphertext.
+        let Output::Private(_, Some(ciphertext)) = self else { bail!("Expected a private output with a ciphertext") };
+        // Compute the output view key as `Hash(function ID || tvk || index)`.
+        let output_view_key = N::hash_psd4(&[function_id, tvk, Field::from_u16(index)])?;
+        // Decrypt the ciphertext.
+        ciphertext.decrypt_symmetric(output_view_key)
+    }
+
     /// Returns the future, if the output is a future.
     pub const fn future(&self) -> Option<&Future<N>> {
         match self {
@@ -145,7 +260,76 @@ impl<N: Network> Output<N> {
         }
     }
 
+    /// Returns the requested entries of the record, if the output is a record owned by the given view key.
+    /// Note: Only the requested entries are returned; the remaining entries of the record are not disclosed.
+    pub fn disclose(
+        &self,
+        view_key: &ViewKey<N>,
+        entries: &[Identifier<N>],
+    ) -> Result<IndexMap<Identifier<N>, Plaintext<N>>> {
+        // Retrieve the record ciphertext.
+        let Some((_, record)) = self.record() else { bail!("Expected a record output with a record ciphertext") };
+        // Decrypt the record.
+        let record = record.decrypt(view_key)?;
+        // Retrieve the requested entries.
+        entries
+            .iter()
+            .map(|name| match record.find(&[*name])? {
+                Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext) => {
+                    Ok((*name, plaintext))
+                }
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the given record plaintext matches the commitment of this record output.
+    /// Note: The record commitment is computed as `Hash(program_id || record_name || record)`.
+    pub fn verify_commitment(
+        &self,
+        program_id: &ProgramID<N>,
+        record_name: &Identifier<N>,
+        record: &Record<N, Plaintext<N>>,
+    ) -> Result<bool> {
+        // Retrieve the commitment.
+        let Output::Record(commitment, ..) = self else { bail!("Expected a record output") };
+        // Ensure the recomputed commitment matches.
+        Ok(*commitment == record.to_commitment(program_id, record_name)?)
+    }
+
+    /// Returns the field elements of the output, which are the ID, the checksum (if the output is a record),
+    /// and the field elements of the value (if it exists).
+    /// Note: A record ciphertext is packed as in `Record::to_fields`, which includes its nonce.
+    pub fn to_fields(&self) -> Result<Vec<Field<N>>> {
+        // Initialize the fields with the ID and checksum.
+        let mut fields = vec![*self.id()];
+        fields.extend(self.checksum().copied());
+        // Append the fields of the value, if it exists.
+        match self {
+            Output::Constant(_, Some(plaintext)) | Output::Public(_, Some(plaintext)) => {
+                fields.extend(plaintext.to_fields()?)
+            }
+            Output::Private(_, Some(ciphertext)) => fields.extend(ciphertext.to_fields()?),
+            Output::Record(_, _, Some(record)) => fields.extend(record.to_fields()?),
+            Output::Future(_, Some(future)) => fields.extend(future.to_fields()?),
+            _ => (),
+        }
+        Ok(fields)
+    }
+
+    /// Returns `true` if the output requires a value, and the value is missing.
+    pub const fn is_value_missing(&self) -> bool {
+        matches!(
+            self,
+            Output::Constant(_, None)
+                | Output::Public(_, None)
+                | Output::Private(_, None)
+                | Output::Record(_, _, None)
+                | Output::Future(_, None)
+        )
+    }
+
     /// Returns the public verifier inputs for the proof.
+    /// The number of inputs is given by `Output::num_verifier_inputs` for the variant of the output.
     pub fn verifier_inputs(&self) -> impl '_ + Iterator<Item = N::Field> {
         // Append the output ID.
         [**self.id()].into_iter()
@@ -157,95 +341,8 @@ impl<N: Network> Output<N> {
     /// If the optional value exists, this method checks that it hashes to the output ID.
     pub fn verify(&self, function_id: Field<N>, tcm: &Field<N>, index: usize) -> bool {
         // Ensure the hash of the value (if the value exists) is correct.
-        let result = || match self {
-            Output::Constant(hash, Some(output)) => {
-                match output.to_fields() {
-                    Ok(fields) => {
-                        // Construct the (console) output index as a field element.
-                        let index = Field::from_u16(index as u16);
-                        // Construct the preimage as `(function ID || output || tcm || index)`.
-                        let mut preimage = Vec::new();
-                        preimage.push(function_id);
-                        preimage.extend(fields);
-                        preimage.push(*tcm);
-                        preimage.push(index);
-                        // Ensure the hash matches.
-                        match N::hash_psd8(&preimage) {
-                            Ok(candidate_hash) => Ok(hash == &candidate_hash),
-                            Err(error) => Err(error),
-                        }
-                    }
-                    Err(error) => Err(error),
-                }
-            }
-            Output::Public(hash, Some(output)) => {
-                match output.to_fields() {
-                    Ok(fields) => {
-                        // Construct the (console) output index as a field element.
-                        let index = Field::from_u16(index as u16);
-                        // Construct the preimage as `(function ID || output || tcm || index)`.
-                        let mut preimage = Vec::new();
-                        preimage.push(function_id);
-                        preimage.extend(fields);
-                        preimage.push(*tcm);
-                        preimage.push(index);
-                        // Ensure the hash matches.
-                        match N::hash_psd8(&preimage) {
-                            Ok(candidate_hash) => Ok(hash == &candidate_hash),
-                            Err(error) => Err(error),
-                        }
-                    }
-                    Err(error) => Err(error),
-                }
-            }
-            Output::Private(hash, Some(value)) => {
-                match value.to_fields() {
-                    // Ensure the hash matches.
-                    Ok(fields) => match N::hash_psd8(&fields) {
-                        Ok(candidate_hash) => Ok(hash == &candidate_hash),
-                        Err(error) => Err(error),
-                    },
-                    Err(error) => Err(error),
-                }
-            }
-            Output::Record(_, checksum, Some(value)) => match N::hash_bhp1024(&value.to_bits_le()) {
-                Ok(candidate_hash) => Ok(checksum == &candidate_hash),
-                Err(error) => Err(error),
-            },
-            Output::Future(hash, Some(output)) => {
-                match output.to_fields() {
-                    Ok(fields) => {
-                        // Construct the (future) output index as a field element.
-                        let index = Field::from_u16(index as u16);
-                        // Construct the preimage as `(function ID || output || tcm || index)`.
-                        let mut preimage = Vec::new();
-                        preimage.push(function_id);
-                        preimage.extend(fields);
-                        preimage.push(*tcm);
-                        preimage.push(index);
-                        // Ensure the hash matches.
-                        match N::hash_psd8(&preimage) {
-                            Ok(candidate_hash) => Ok(hash == &candidate_hash),
-                            Err(error) => Err(error),
-                        }
-                    }
-                    Err(error) => Err(error),
-                }
-            }
-            Output::Constant(_, None)
-            | Output::Public(_, None)
-            | Output::Private(_, None)
-            | Output::Record(_, _, None)
-            | Output::Future(_, None) => {
-                // This enforces that the transition *must* contain the value for this transition output.
-                // A similar rule is enforced for the transition input.
-                bail!("A transition output value is missing")
-            }
-            Output::ExternalRecord(_) => Ok(true),
-        };
-
-        match result() {
-            Ok(is_hash_valid) => is_hash_valid,
+        match self.explain_verify(function_id, tcm, index) {
+            Ok(report) => report.is_valid(),
             Err(error) => {
                 eprintln!("{error}");
                 false
@@ -276,6 +373,15 @@ pub(crate) mod test_helpers {
         // Sample a random plaintext.
         let plaintext = Plaintext::Literal(Literal::Field(Uniform::rand(rng)), Default::default());
         let plaintext_hash = CurrentNetwork::hash_bhp1024(&plaintext.to_bits_le()).unwrap();
+        // Sample a random struct plaintext.
+        let struct_plaintext = Plaintext::from_str(&format!(
+            "{{ point: {{ x: {}, y: {} }}, label: {}u8 }}",
+            Field::<CurrentNetwork>::rand(rng),
+            Field::<CurrentNetwork>::rand(rng),
+            u8::rand(rng)
+        ))
+        .unwrap();
+        let struct_plaintext_hash = CurrentNetwork::hash_bhp1024(&struct_plaintext.to_bits_le()).unwrap();
         // Sample a random ciphertext.
         let fields: Vec<_> = (0..10).map(|_| Uniform::rand(rng)).collect();
         let ciphertext = Ciphertext::from_fields(&fields).unwrap();
@@ -295,6 +401,8 @@ pub(crate) mod test_helpers {
             (Uniform::rand(rng), Output::Constant(plaintext_hash, Some(plaintext.clone()))),
             (Uniform::rand(rng), Output::Public(Uniform::rand(rng), None)),
             (Uniform::rand(rng), Output::Public(plaintext_hash, Some(plaintext))),
+            (Uniform::rand(rng), Output::Constant(struct_plaintext_hash, Some(struct_plaintext.clone()))),
+            (Uniform::rand(rng), Output::Public(struct_plaintext_hash, Some(struct_plaintext))),
             (Uniform::rand(rng), Output::Private(Uniform::rand(rng), None)),
             (Uniform::rand(rng), Output::Private(ciphertext_hash, Some(ciphertext))),
             (Uniform::rand(rng), Output::Record(Uniform::rand(rng), Uniform::rand(rng), None)),
@@ -303,3 +411,499 @@ pub(crate) mod test_helpers {
         ]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Transition;
+    use console::{account::PrivateKey, network::MainnetV0, program::Argument, types::Address};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_disclose() {
+        let rng = &mut TestRng::default();
+
+        // Sample an account.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let owner = Address::try_from(&private_key).unwrap();
+
+        // Sample a record output.
+        let randomizer = Uniform::rand(rng);
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {owner}.private, balance: 5u64.private, token_amount: 100u64.private, _nonce: {nonce}.public }}"
+        ))
+        .unwrap();
+        let record_ciphertext = record.encrypt(randomizer).unwrap();
+        let record_checksum = CurrentNetwork::hash_bhp1024(&record_ciphertext.to_bits_le()).unwrap();
+        let output = Output::Record(Uniform::rand(rng), record_checksum, Some(record_ciphertext));
+
+        // Disclose the token amount.
+        let token_amount = Identifier::from_str("token_amount").unwrap();
+        let disclosed = output.disclose(&view_key, &[token_amount]).unwrap();
+        assert_eq!(disclosed.len(), 1);
+        assert_eq!(disclosed[&token_amount], Plaintext::from_str("100u64").unwrap());
+        // Ensure the other entries remain hidden.
+        assert!(!disclosed.contains_key(&Identifier::from_str("balance").unwrap()));
+        assert!(!disclosed.contains_key(&Identifier::from_str("owner").unwrap()));
+
+        // Ensure a missing entry fails.
+        assert!(output.disclose(&view_key, &[Identifier::from_str("missing").unwrap()]).is_err());
+        // Ensure a different view key fails.
+        let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        assert!(output.disclose(&other_view_key, &[token_amount]).is_err());
+        // Ensure a non-record output fails.
+        let output = Output::<CurrentNetwork>::Public(Uniform::rand(rng), None);
+        assert!(output.disclose(&view_key, &[token_amount]).is_err());
+    }
+
+    #[test]
+    fn test_as_private_view() {
+        for (_, output) in crate::transition::output::test_helpers::sample_outputs() {
+            match &output {
+                // Ensure a private output returns its ciphertext hash and ciphertext.
+                Output::Private(id, Some(ciphertext)) => {
+                    assert_eq!(output.as_private_view().unwrap(), PrivateView::Private(id, ciphertext));
+                }
+                // Ensure a record output returns its commitment, checksum, and record ciphertext.
+                Output::Record(commitment, checksum, Some(record)) => {
+                    assert_eq!(output.as_private_view().unwrap(), PrivateView::Record(commitment, checksum, record));
+                }
+                // Ensure the remaining outputs have no private view.
+                _ => assert!(output.as_private_view().is_err()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_decrypt_private() {
+        let rng = &mut TestRng::default();
+
+        // Sample the function ID, transition view key, and output index.
+        let function_id = Uniform::rand(rng);
+        let tvk = Uniform::rand(rng);
+        let index = 3;
+
+        // Encrypt a struct as a private output.
+        let plaintext =
+            Plaintext::<CurrentNetwork>::from_str("{ amount: 5u64, inner: { flag: true, value: 7field } }").unwrap();
+        let output_view_key = CurrentNetwork::hash_psd4(&[function_id, tvk, Field::from_u16(index)]).unwrap();
+        let ciphertext = plaintext.encrypt_symmetric(output_view_key).unwrap();
+        let id = CurrentNetwork::hash_psd8(&ciphertext.to_fields().unwrap()).unwrap();
+        let output = Output::Private(id, Some(ciphertext));
+
+        // Ensure the struct is reconstructed, including its members.
+        let candidate = output.decrypt_private(function_id, tvk, index).unwrap();
+        assert_eq!(candidate, plaintext);
+        let path = [Identifier::from_str("inner").unwrap(), Identifier::from_str("value").unwrap()];
+        assert_eq!(candidate.find(&path).unwrap(), Plaintext::from_str("7field").unwrap());
+
+        // Ensure a different index does not recover the struct.
+        assert_ne!(output.decrypt_private(function_id, tvk, index + 1).ok(), Some(plaintext.clone()));
+        // Ensure a wrong transition view key does not recover the struct.
+        assert_ne!(output.decrypt_private(function_id, Uniform::rand(rng), index).ok(), Some(plaintext.clone()));
+        // Ensure a wrong function ID does not recover the struct.
+        assert_ne!(output.decrypt_private(Uniform::rand(rng), tvk, index).ok(), Some(plaintext));
+
+        // Ensure a private output without a ciphertext, and a public output, are rejected.
+        assert!(Output::<CurrentNetwork>::Private(id, None).decrypt_private(function_id, tvk, index).is_err());
+        assert!(Output::<CurrentNetwork>::Public(id, None).decrypt_private(function_id, tvk, index).is_err());
+    }
+
+    #[test]
+    fn test_to_fields() {
+        // Sample the outputs, including a future output.
+        let mut outputs = crate::transition::output::test_helpers::sample_outputs();
+        let future = Future::new(
+            ProgramID::from_str("credits.aleo").unwrap(),
+            Identifier::from_str("transfer_public").unwrap(),
+            vec![Argument::Plaintext(Plaintext::from_str("5u64").unwrap())],
+        );
+        outputs.push((outputs[0].0, Output::Future(Field::from_u8(1), None)));
+        outputs.push((outputs[0].0, Output::Future(Field::from_u8(2), Some(future))));
+
+        for (_, output) in outputs {
+            let fields = output.to_fields().unwrap();
+            let bytes = output.to_bytes_le().unwrap();
+            // Ensure the fields start with the ID, which is encoded after the variant in the bytes.
+            assert_eq!(fields[0], *output.id());
+            assert_eq!(fields[0].to_bytes_le().unwrap(), bytes[1..33]);
+            // Ensure the fields are unchanged after a round trip through bytes.
+            assert_eq!(Output::read_le(&bytes[..]).unwrap().to_fields().unwrap(), fields);
+            match &output {
+                // Ensure a record output is its commitment, checksum, and record ciphertext,
+                // and the checksum is encoded after the commitment in the bytes.
+                Output::Record(_, checksum, record) => {
+                    assert_eq!(fields[1], *checksum);
+                    assert_eq!(fields[1].to_bytes_le().unwrap(), bytes[33..65]);
+                    match record {
+                        Some(record) => assert_eq!(fields.len(), 2 + record.to_fields().unwrap().len()),
+                        None => assert_eq!(fields.len(), 2),
+                    }
+                }
+                // Ensure a private output is its ID and ciphertext, and each ciphertext field element
+                // is encoded in the bytes (after the variant, ID, presence flag, and length).
+                Output::Private(_, Some(ciphertext)) => {
+                    assert_eq!(fields[1..], ciphertext.to_fields().unwrap());
+                    assert_eq!(bytes.len(), 1 + 32 + 1 + 2 + 32 * (fields.len() - 1));
+                    assert_eq!(
+                        fields[1..].iter().flat_map(|field| field.to_bytes_le().unwrap()).collect::<Vec<_>>(),
+                        bytes[36..]
+                    );
+                }
+                // Ensure an output without a value is only its ID.
+                _ if output.is_value_missing() => assert_eq!(fields, [*output.id()]),
+                Output::ExternalRecord(..) => assert_eq!(fields, [*output.id()]),
+                // Ensure an output with a value is its ID and the fields of the value.
+                Output::Constant(_, Some(plaintext)) | Output::Public(_, Some(plaintext)) => {
+                    assert_eq!(fields[1..], plaintext.to_fields().unwrap())
+                }
+                Output::Future(_, Some(future)) => assert_eq!(fields[1..], future.to_fields().unwrap()),
+                _ => unreachable!("All output variants are covered"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_storage_key() {
+        let rng = &mut TestRng::default();
+
+        // Ensure outputs with equal IDs produce equal keys, regardless of the payload.
+        let id = Uniform::rand(rng);
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("5u64").unwrap();
+        let a = Output::Public(id, Some(plaintext));
+        let b = Output::<CurrentNetwork>::Public(id, None);
+        assert_eq!(a.storage_key().unwrap(), b.storage_key().unwrap());
+        assert_eq!(a.storage_key().unwrap(), id.to_bytes_le().unwrap());
+
+        // Ensure outputs with different IDs produce different keys.
+        let c = Output::<CurrentNetwork>::Public(Uniform::rand(rng), None);
+        assert_ne!(a.storage_key().unwrap(), c.storage_key().unwrap());
+
+        // Ensure a record output is keyed by its commitment.
+        let commitment = Uniform::rand(rng);
+        let record = Output::<CurrentNetwork>::Record(commitment, Uniform::rand(rng), None);
+        assert_eq!(record.storage_key().unwrap(), commitment.to_bytes_le().unwrap());
+
+        // Ensure the key is unique per output ID, and is stable across serialization.
+        let outputs = crate::transition::output::test_helpers::sample_outputs();
+        let mut keys = IndexMap::new();
+        for (_, output) in &outputs {
+            let key = output.storage_key().unwrap();
+            // Ensure the key is unchanged after a round trip through bytes and JSON.
+            let bytes_output = Output::read_le(&output.to_bytes_le().unwrap()[..]).unwrap();
+            assert_eq!(bytes_output.storage_key().unwrap(), key);
+            let json_output: Output<CurrentNetwork> =
+                serde_json::from_str(&serde_json::to_string(output).unwrap()).unwrap();
+            assert_eq!(json_output.storage_key().unwrap(), key);
+            // Ensure a key is only shared by outputs with the same ID.
+            assert_eq!(keys.entry(key).or_insert(*output.id()), output.id());
+        }
+        assert_eq!(
+            keys.len(),
+            outputs.iter().map(|(_, output)| *output.id()).collect::<std::collections::HashSet<_>>().len()
+        );
+    }
+
+    #[test]
+    fn test_verify_outputs() {
+        let rng = &mut TestRng::default();
+
+        // Sample the function ID and transition commitment.
+        let function_id = Uniform::rand(rng);
+        let tcm = Uniform::rand(rng);
+
+        // Construct a public output at index 0.
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("5u64").unwrap();
+        let mut preimage = vec![function_id];
+        preimage.extend(plaintext.to_fields().unwrap());
+        preimage.extend([tcm, Field::from_u16(0)]);
+        let public = Output::Public(CurrentNetwork::hash_psd8(&preimage).unwrap(), Some(plaintext));
+        // Construct an external record output at index 1.
+        let external = Output::ExternalRecord(Uniform::rand(rng));
+
+        // Construct the transition.
+        let transition = Transition::new(
+            ProgramID::from_str("token.aleo").unwrap(),
+            Identifier::from_str("mint").unwrap(),
+            vec![],
+            vec![public, external],
+            Uniform::rand(rng),
+            tcm,
+            Uniform::rand(rng),
+        )
+        .unwrap();
+
+        // Ensure the batch verification matches the per-output verification.
+        assert!(transition.verify_outputs(function_id));
+        assert!(transition.outputs().iter().enumerate().all(|(index, output)| output.verify(function_id, &tcm, index)));
+
+        // Ensure the batch verification fails for the wrong function ID.
+        assert!(!transition.verify_outputs(Uniform::rand(rng)));
+
+        // Strip the value from each output.
+        let outputs = transition
+            .outputs()
+            .iter()
+            .map(|output| match output {
+                Output::Constant(id, _) => Output::Constant(*id, None),
+                Output::Public(id, _) => Output::Public(*id, None),
+                Output::Private(id, _) => Output::Private(*id, None),
+                Output::Record(commitment, checksum, _) => Output::Record(*commitment, *checksum, None),
+                Output::Future(id, _) => Output::Future(*id, None),
+                Output::ExternalRecord(id) => Output::ExternalRecord(*id),
+            })
+            .collect::<Vec<_>>();
+        assert!(outputs.iter().any(Output::is_value_missing));
+        let stripped = Transition::new(
+            *transition.program_id(),
+            *transition.function_name(),
+            transition.inputs().to_vec(),
+            outputs,
+            *transition.tpk(),
+            *transition.tcm(),
+            *transition.scm(),
+        )
+        .unwrap();
+
+        // Ensure the batch verification rejects the outputs with a missing value.
+        assert!(!stripped.verify_outputs(function_id));
+    }
+
+    #[test]
+    fn test_explain_verify() {
+        let rng = &mut TestRng::default();
+
+        // Sample the function ID and transition commitment.
+        let function_id = Uniform::rand(rng);
+        let tcm = Uniform::rand(rng);
+
+        // Construct a public output at index 0.
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("5u64").unwrap();
+        let mut preimage = vec![function_id];
+        preimage.extend(plaintext.to_fields().unwrap());
+        preimage.extend([tcm, Field::from_u16(0)]);
+        let id = CurrentNetwork::hash_psd8(&preimage).unwrap();
+        let output = Output::Public(id, Some(plaintext));
+
+        // Ensure the report is valid for a well-formed output.
+        let report = output.explain_verify(function_id, &tcm, 0).unwrap();
+        assert!(report.is_valid());
+        assert_eq!(report.expected(), report.computed());
+        assert!(output.verify(function_id, &tcm, 0));
+
+        // Corrupt the output value.
+        let corrupted = Output::Public(id, Some(Plaintext::from_str("6u64").unwrap()));
+        let report = corrupted.explain_verify(function_id, &tcm, 0).unwrap();
+        assert!(!report.is_valid());
+        assert_eq!(report.field(), "id");
+        assert_eq!(report.expected(), &id);
+        assert_ne!(report.computed(), &id);
+        assert!(report.to_string().starts_with("Output id mismatch"));
+        assert!(!corrupted.verify(function_id, &tcm, 0));
+
+        // Corrupt the checksum of a record output.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let owner = Address::try_from(&private_key).unwrap();
+        let randomizer = Uniform::rand(rng);
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {owner}.private, token_amount: 100u64.private, _nonce: {nonce}.public }}"
+        ))
+        .unwrap();
+        let corrupted =
+            Output::Record(Uniform::rand(rng), Uniform::rand(rng), Some(record.encrypt(randomizer).unwrap()));
+        let report = corrupted.explain_verify(function_id, &tcm, 1).unwrap();
+        assert!(!report.is_valid());
+        assert_eq!(report.field(), "checksum");
+
+        // Ensure a missing value is reported as an error.
+        assert!(Output::<CurrentNetwork>::Public(id, None).explain_verify(function_id, &tcm, 0).is_err());
+
+        // Ensure an external record with a zero ID is rejected.
+        let external = Output::<CurrentNetwork>::ExternalRecord(Field::zero());
+        assert!(external.explain_verify(function_id, &tcm, 2).is_err());
+        assert!(!external.verify(function_id, &tcm, 2));
+        assert!(Output::<CurrentNetwork>::ExternalRecord(id).verify(function_id, &tcm, 2));
+    }
+
+    #[test]
+    fn test_verify_rejects_swapped_nonce() {
+        let rng = &mut TestRng::default();
+
+        // Sample the function ID and transition commitment.
+        let function_id = Uniform::rand(rng);
+        let tcm = Uniform::rand(rng);
+
+        // Sample a record output.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let owner = Address::try_from(&private_key).unwrap();
+        let randomizer = Uniform::rand(rng);
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {owner}.private, token_amount: 100u64.private, _nonce: {nonce}.public }}"
+        ))
+        .unwrap();
+        let record_ciphertext = record.encrypt(randomizer).unwrap();
+        let checksum = CurrentNetwork::hash_bhp1024(&record_ciphertext.to_bits_le()).unwrap();
+        let output = Output::Record(Uniform::rand(rng), checksum, Some(record_ciphertext.clone()));
+        assert!(output.verify(function_id, &tcm, 1));
+
+        // Swap the nonce of the record ciphertext.
+        let swapped_nonce = CurrentNetwork::g_scalar_multiply(&Uniform::rand(rng));
+        let swapped = Record::<CurrentNetwork, Ciphertext<CurrentNetwork>>::from_str(
+            &record_ciphertext.to_string().replace(&nonce.to_string(), &swapped_nonce.to_string()),
+        )
+        .unwrap();
+        assert_eq!(swapped.nonce(), &swapped_nonce);
+
+        // Ensure the output with the swapped nonce is rejected, as the checksum covers the nonce.
+        let output = Output::Record(*output.id(), checksum, Some(swapped));
+        assert_eq!(output.nonce(), Some(&swapped_nonce));
+        let report = output.explain_verify(function_id, &tcm, 1).unwrap();
+        assert!(!report.is_valid());
+        assert_eq!(report.field(), "checksum");
+        assert!(!output.verify(function_id, &tcm, 1));
+    }
+
+    #[test]
+    fn test_verify_commitment() {
+        let rng = &mut TestRng::default();
+
+        // Sample an account.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let owner = Address::try_from(&private_key).unwrap();
+
+        // Derive the randomizer from a known transition view key and output index.
+        let tvk = Field::<CurrentNetwork>::rand(rng);
+        let index = Field::from_u64(2);
+        let randomizer = CurrentNetwork::hash_to_scalar_psd2(&[tvk, index]).unwrap();
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+
+        // Construct the record output.
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let record_name = Identifier::from_str("token").unwrap();
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {owner}.private, token_amount: 100u64.private, _nonce: {nonce}.public }}"
+        ))
+        .unwrap();
+        let commitment = record.to_commitment(&program_id, &record_name).unwrap();
+        let ciphertext = record.encrypt(randomizer).unwrap();
+        let checksum = CurrentNetwork::hash_bhp1024(&ciphertext.to_bits_le()).unwrap();
+        let output = Output::Record(commitment, checksum, Some(ciphertext));
+
+        // Ensure the decrypted record matches the commitment.
+        let decrypted = output.record().unwrap().1.decrypt(&ViewKey::try_from(&private_key).unwrap()).unwrap();
+        assert_eq!(decrypted, record);
+        assert!(output.verify_commitment(&program_id, &record_name, &decrypted).unwrap());
+
+        // Ensure a different record, program ID, or record name does not match the commitment.
+        let other = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {owner}.private, token_amount: 101u64.private, _nonce: {nonce}.public }}"
+        ))
+        .unwrap();
+        assert!(!output.verify_commitment(&program_id, &record_name, &other).unwrap());
+        let other_program_id = ProgramID::from_str("other.aleo").unwrap();
+        assert!(!output.verify_commitment(&other_program_id, &record_name, &decrypted).unwrap());
+        let other_record_name = Identifier::from_str("other").unwrap();
+        assert!(!output.verify_commitment(&program_id, &other_record_name, &decrypted).unwrap());
+
+        // Ensure a non-record output fails.
+        let output = Output::<CurrentNetwork>::Public(Uniform::rand(rng), None);
+        assert!(output.verify_commitment(&program_id, &record_name, &decrypted).is_err());
+    }
+
+    #[test]
+    fn test_variant_domain_separation() {
+        let rng = &mut TestRng::default();
+
+        // Sample an output ID.
+        let id = Field::<CurrentNetwork>::rand(rng);
+
+        // Construct outputs of different variants with the same ID.
+        let outputs = [
+            Output::<CurrentNetwork>::Constant(id, None),
+            Output::Public(id, None),
+            Output::Private(id, None),
+            Output::Record(id, Uniform::rand(rng), None),
+            Output::ExternalRecord(id),
+            Output::Future(id, None),
+        ];
+
+        // Ensure the transition leaves are distinct.
+        let leaves = outputs.iter().map(|output| output.to_transition_leaf(0)).collect::<Vec<_>>();
+        let hashes =
+            leaves.iter().map(|leaf| CurrentNetwork::hash_bhp512(&leaf.to_bits_le()).unwrap()).collect::<Vec<_>>();
+        for (i, hash) in hashes.iter().enumerate() {
+            for other in &hashes[i + 1..] {
+                assert_ne!(hash, other);
+            }
+        }
+
+        // Ensure the transition IDs are distinct.
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let function_name = Identifier::from_str("mint").unwrap();
+        let (tpk, tcm, scm) = (Uniform::rand(rng), Uniform::rand(rng), Uniform::rand(rng));
+        let constant = Transition::new(program_id, function_name, vec![], vec![outputs[0].clone()], tpk, tcm, scm);
+        let public = Transition::new(program_id, function_name, vec![], vec![outputs[1].clone()], tpk, tcm, scm);
+        assert_ne!(constant.unwrap().id(), public.unwrap().id());
+    }
+
+    #[test]
+    fn test_content_id() {
+        let rng = &mut TestRng::default();
+
+        // Sample an output ID.
+        let id = Field::<CurrentNetwork>::rand(rng);
+
+        // Construct outputs of different variants with the same ID.
+        let outputs = [
+            Output::<CurrentNetwork>::Constant(id, None),
+            Output::Public(id, None),
+            Output::Private(id, None),
+            Output::Record(id, Uniform::rand(rng), None),
+            Output::ExternalRecord(id),
+            Output::Future(id, None),
+        ];
+
+        // Ensure the content IDs are distinct.
+        let content_ids = outputs.iter().map(|output| output.content_id().unwrap()).collect::<Vec<_>>();
+        for (i, content_id) in content_ids.iter().enumerate() {
+            for other in &content_ids[i + 1..] {
+                assert_ne!(content_id, other);
+            }
+        }
+
+        // Ensure the content ID does not depend on the payload.
+        let plaintext = Plaintext::from_str("5u64").unwrap();
+        assert_eq!(Output::Public(id, Some(plaintext)).content_id().unwrap(), content_ids[1]);
+        // Ensure the content ID of a record output depends on the checksum.
+        assert_ne!(Output::Record(id, Uniform::rand(rng), None).content_id().unwrap(), content_ids[3]);
+    }
+
+    #[test]
+    fn test_hash() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the sample outputs are distinct members of a set.
+        let outputs = test_helpers::sample_outputs().into_iter().map(|(_, output)| output).collect::<Vec<_>>();
+        let set = outputs.iter().cloned().collect::<std::collections::HashSet<_>>();
+        assert_eq!(set.len(), outputs.len());
+        assert!(outputs.iter().all(|output| set.contains(output)));
+
+        // Ensure outputs of different variants with the same ID are distinct members of a set.
+        let id = Field::<CurrentNetwork>::rand(rng);
+        let outputs = [
+            Output::<CurrentNetwork>::Constant(id, None),
+            Output::Public(id, None),
+            Output::Private(id, None),
+            Output::Record(id, Uniform::rand(rng), None),
+            Output::ExternalRecord(id),
+            Output::Future(id, None),
+        ];
+        let set = outputs.iter().cloned().collect::<std::collections::HashSet<_>>();
+        assert_eq!(set.len(), outputs.len());
+    }
+}
@@ -0,0 +1,211 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+use console::{
+    account::{PrivateKey, Signature},
+    program::Owner,
+    types::Address,
+};
+
+/// A proof of ownership of a record output, for a challenge that is chosen by the verifier.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OwnershipProof<N: Network> {
+    /// The signature of the owner over `(domain || commitment || checksum || challenge)`.
+    signature: Signature<N>,
+    /// The record view key, if the record has a private owner.
+    record_view_key: Option<Field<N>>,
+}
+
+impl<N: Network> OwnershipProof<N> {
+    /// Returns the signature of the owner.
+    pub const fn signature(&self) -> &Signature<N> {
+        &self.signature
+    }
+
+    /// Returns the record view key, if the record has a private owner.
+    pub const fn record_view_key(&self) -> Option<&Field<N>> {
+        self.record_view_key.as_ref()
+    }
+}
+
+impl<N: Network> Output<N> {
+    /// Returns a proof that the given private key owns the record output, for the given verifier challenge.
+    /// The proof is a signature by the owner over `(domain || commitment || checksum || challenge)`,
+    /// so it is bound to the record, and can not be replayed for another challenge.
+    ///
+    /// For a record with a public owner, the proof does not reveal the record entries.
+    /// For a record with a private owner, the proof includes the record view key, which the verifier
+    /// uses to decrypt the owner. Note: This discloses the record entries to the verifier.
+    pub fn prove_ownership<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        challenge: Field<N>,
+        rng: &mut R,
+    ) -> Result<OwnershipProof<N>> {
+        // Retrieve the commitment, checksum, and record ciphertext.
+        let (commitment, checksum, record) = self.record_ciphertext()?;
+        // Compute the record view key, if the owner is private.
+        let record_view_key = match record.owner() {
+            Owner::Public(owner) => {
+                // Ensure the private key belongs to the owner.
+                ensure!(Address::try_from(private_key)? == *owner, "The private key does not own the record");
+                None
+            }
+            Owner::Private(..) => {
+                // Ensure the private key belongs to the owner.
+                let view_key = ViewKey::try_from(private_key)?;
+                ensure!(record.is_owner(&view_key), "The private key does not own the record");
+                Some((*record.nonce() * *view_key).to_x_coordinate())
+            }
+        };
+        // Sign the message.
+        let signature = private_key.sign(&Self::ownership_message(commitment, checksum, challenge), rng)?;
+        Ok(OwnershipProof { signature, record_view_key })
+    }
+
+    /// Returns `true` if the given proof shows ownership of the record output, for the given verifier challenge.
+    pub fn verify_ownership(&self, proof: &OwnershipProof<N>, challenge: Field<N>) -> bool {
+        // Retrieve the commitment, checksum, and record ciphertext.
+        let Ok((commitment, checksum, record)) = self.record_ciphertext() else {
+            return false;
+        };
+        // Retrieve the owner of the record.
+        let owner = match (record.owner(), proof.record_view_key) {
+            (Owner::Public(owner), None) => *owner,
+            // Decrypt the owner with the record view key. As the owner ciphertext is masked by a randomizer
+            // that is a hash of the record view key, a wrong record view key does not recover the owner.
+            (Owner::Private(..), Some(record_view_key)) => match record.decrypt_symmetric_unchecked(&record_view_key) {
+                Ok(record) => **record.owner(),
+                Err(_) => return false,
+            },
+            _ => return false,
+        };
+        // Verify the signature against the owner.
+        proof.signature.verify(&owner, &Self::ownership_message(commitment, checksum, challenge))
+    }
+
+    /// Returns the message that is signed to prove ownership of a record output,
+    /// as `(domain || commitment || checksum || challenge)`.
+    fn ownership_message(commitment: Field<N>, checksum: Field<N>, challenge: Field<N>) -> [Field<N>; 4] {
+        [Field::new_domain_separator("AleoOwnershipProof"), commitment, checksum, challenge]
+    }
+
+    /// Returns the commitment, checksum, and record ciphertext of the record output.
+    fn record_ciphertext(&self) -> Result<(Field<N>, Field<N>, &Record<N, Ciphertext<N>>)> {
+        match self {
+            Output::Record(commitment, checksum, Some(record)) => Ok((*commitment, *checksum, record)),
+            _ => bail!("Expected a record output with a record ciphertext"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Returns a record output owned by the given address, with the given visibility of the owner.
+    fn sample_record_output(
+        owner: Address<CurrentNetwork>,
+        visibility: &str,
+        rng: &mut TestRng,
+    ) -> Output<CurrentNetwork> {
+        let randomizer = Uniform::rand(rng);
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {owner}.{visibility}, token_amount: 100u64.private, _nonce: {nonce}.public }}"
+        ))
+        .unwrap();
+        let ciphertext = record.encrypt(randomizer).unwrap();
+        let checksum = CurrentNetwork::hash_bhp1024(&ciphertext.to_bits_le()).unwrap();
+        Output::Record(Uniform::rand(rng), checksum, Some(ciphertext))
+    }
+
+    #[test]
+    fn test_prove_ownership() {
+        let rng = &mut TestRng::default();
+
+        // Sample an account.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let owner = Address::try_from(&private_key).unwrap();
+        let challenge = Uniform::rand(rng);
+
+        // Ensure the owner can prove ownership of the record output.
+        let output = sample_record_output(owner, "public", rng);
+        let proof = output.prove_ownership(&private_key, challenge, rng).unwrap();
+        assert!(proof.record_view_key().is_none());
+        assert!(output.verify_ownership(&proof, challenge));
+
+        // Ensure the proof can not be replayed for another challenge.
+        assert!(!output.verify_ownership(&proof, Uniform::rand(rng)));
+
+        // Ensure the proof does not verify for another record output of the same owner.
+        let other_output = sample_record_output(owner, "public", rng);
+        assert!(!other_output.verify_ownership(&proof, challenge));
+
+        // Ensure a signature over the commitment, checksum, and challenge, without the domain, is rejected.
+        let (commitment, checksum) = (*output.id(), *output.checksum().unwrap());
+        let signature = private_key.sign(&[commitment, checksum, challenge], rng).unwrap();
+        assert!(!output.verify_ownership(&OwnershipProof { signature, record_view_key: None }, challenge));
+
+        // Ensure another account can not prove ownership.
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        assert!(output.prove_ownership(&other_private_key, challenge, rng).is_err());
+        let message = Output::<CurrentNetwork>::ownership_message(commitment, checksum, challenge);
+        let signature = other_private_key.sign(&message, rng).unwrap();
+        assert!(!output.verify_ownership(&OwnershipProof { signature, record_view_key: None }, challenge));
+    }
+
+    #[test]
+    fn test_prove_ownership_private_owner() {
+        let rng = &mut TestRng::default();
+
+        // Sample an account.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let owner = Address::try_from(&private_key).unwrap();
+        let challenge = Uniform::rand(rng);
+
+        // Ensure the owner can prove ownership of a record output with a private owner.
+        let output = sample_record_output(owner, "private", rng);
+        let proof = output.prove_ownership(&private_key, challenge, rng).unwrap();
+        assert!(proof.record_view_key().is_some());
+        assert!(output.verify_ownership(&proof, challenge));
+
+        // Ensure the proof can not be replayed for another challenge.
+        assert!(!output.verify_ownership(&proof, Uniform::rand(rng)));
+
+        // Ensure the proof does not verify for another record output of the same owner.
+        let other_output = sample_record_output(owner, "private", rng);
+        assert!(!other_output.verify_ownership(&proof, challenge));
+
+        // Ensure the proof does not verify without the record view key, or with a wrong record view key.
+        let signature = *proof.signature();
+        let without_key = OwnershipProof { signature, record_view_key: None };
+        assert!(!output.verify_ownership(&without_key, challenge));
+        let wrong_key = OwnershipProof { signature, record_view_key: Some(Uniform::rand(rng)) };
+        assert!(!output.verify_ownership(&wrong_key, challenge));
+
+        // Ensure another account can not prove ownership, even with the record view key of the owner.
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        assert!(output.prove_ownership(&other_private_key, challenge, rng).is_err());
+        let (commitment, checksum) = (*output.id(), *output.checksum().unwrap());
+        let message = Output::<CurrentNetwork>::ownership_message(commitment, checksum, challenge);
+        let signature = other_private_key.sign(&message, rng).unwrap();
+        let record_view_key = proof.record_view_key().copied();
+        assert!(!output.verify_ownership(&OwnershipProof { signature, record_view_key }, challenge));
+    }
+}
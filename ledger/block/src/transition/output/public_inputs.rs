@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The public verifier inputs of the outputs of a transition, in order.
+/// Each output contributes its ID, followed by its checksum if the output is a record.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicInputs<N: Network> {
+    /// The ID and (optional) checksum of each output.
+    outputs: Vec<(N::Field, Option<N::Field>)>,
+}
+
+impl<N: Network> PublicInputs<N> {
+    /// Initializes the public inputs from the given transition outputs.
+    pub fn from_outputs(outputs: &[Output<N>]) -> Self {
+        Self { outputs: outputs.iter().map(|output| (**output.id(), output.checksum().map(|sum| **sum))).collect() }
+    }
+
+    /// Initializes the public inputs from the given field elements, for outputs of the given variants.
+    /// This ensures the number of field elements matches the number expected for the variants.
+    pub fn from_vec(fields: Vec<N::Field>, variants: &[Variant]) -> Result<Self> {
+        // Compute the expected number of field elements, as a record output also contributes its checksum.
+        let expected = variants
+            .iter()
+            .map(|variant| {
+                Output::<N>::num_verifier_inputs(*variant).ok_or_else(|| anyhow!("Invalid output variant {variant}"))
+            })
+            .sum::<Result<usize>>()?;
+        ensure!(fields.len() == expected, "Expected {expected} public inputs for the outputs, found {}", fields.len());
+
+        // Reconstruct the ID and (optional) checksum of each output.
+        let mut fields = fields.into_iter();
+        let outputs = variants
+            .iter()
+            .map(|variant| (fields.next().unwrap(), (*variant == RECORD_VARIANT).then(|| fields.next().unwrap())))
+            .collect();
+        Ok(Self { outputs })
+    }
+
+    /// Returns the number of outputs.
+    pub fn num_outputs(&self) -> usize {
+        self.outputs.len()
+    }
+
+    /// Returns the public inputs as a vector of field elements.
+    pub fn to_vec(&self) -> Vec<N::Field> {
+        self.outputs.iter().flat_map(|(id, checksum)| [Some(*id), *checksum]).flatten().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_public_inputs() {
+        let outputs = crate::transition::output::test_helpers::sample_outputs()
+            .into_iter()
+            .map(|(_, output)| output)
+            .collect::<Vec<Output<CurrentNetwork>>>();
+        let variants = outputs.iter().map(Output::variant).collect::<Vec<_>>();
+
+        // Ensure each output contributes the expected number of verifier inputs.
+        for output in &outputs {
+            let expected = Output::<CurrentNetwork>::num_verifier_inputs(output.variant());
+            assert_eq!(Some(output.verifier_inputs().count()), expected);
+        }
+
+        // Ensure the public inputs match the verifier inputs of the outputs, in order.
+        let public_inputs = PublicInputs::from_outputs(&outputs);
+        let expected = outputs.iter().flat_map(Output::verifier_inputs).collect::<Vec<_>>();
+        assert_eq!(public_inputs.num_outputs(), outputs.len());
+        assert_eq!(public_inputs.to_vec(), expected);
+
+        // Ensure the public inputs round-trip through a vector.
+        let candidate = PublicInputs::<CurrentNetwork>::from_vec(public_inputs.to_vec(), &variants).unwrap();
+        assert_eq!(candidate, public_inputs);
+    }
+
+    #[test]
+    fn test_public_inputs_rejects_length_mismatch() {
+        let outputs = crate::transition::output::test_helpers::sample_outputs()
+            .into_iter()
+            .map(|(_, output)| output)
+            .collect::<Vec<Output<CurrentNetwork>>>();
+        let variants = outputs.iter().map(Output::variant).collect::<Vec<_>>();
+        let mut fields = PublicInputs::from_outputs(&outputs).to_vec();
+
+        // Ensure a missing field element is rejected.
+        let last = fields.pop().unwrap();
+        assert!(PublicInputs::<CurrentNetwork>::from_vec(fields.clone(), &variants).is_err());
+        // Ensure an extra field element is rejected.
+        fields.extend([last, last]);
+        assert!(PublicInputs::<CurrentNetwork>::from_vec(fields, &variants).is_err());
+        // Ensure an invalid variant is rejected.
+        assert!(PublicInputs::<CurrentNetwork>::from_vec(vec![last], &[6]).is_err());
+    }
+}
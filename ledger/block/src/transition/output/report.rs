@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A report comparing the expected hash of a transition output to the hash computed from its value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OutputVerifyReport<N: Network> {
+    /// The name of the field that is checked, i.e. `id` or `checksum`.
+    field: &'static str,
+    /// The hash stored in the output.
+    expected: Field<N>,
+    /// The hash computed from the output value.
+    computed: Field<N>,
+}
+
+impl<N: Network> OutputVerifyReport<N> {
+    /// Returns the name of the field that is checked, i.e. `id` or `checksum`.
+    pub const fn field(&self) -> &'static str {
+        self.field
+    }
+
+    /// Returns the hash stored in the output.
+    pub const fn expected(&self) -> &Field<N> {
+        &self.expected
+    }
+
+    /// Returns the hash computed from the output value.
+    pub const fn computed(&self) -> &Field<N> {
+        &self.computed
+    }
+
+    /// Returns `true` if the expected hash matches the computed hash.
+    pub fn is_valid(&self) -> bool {
+        self.expected == self.computed
+    }
+}
+
+impl<N: Network> Display for OutputVerifyReport<N> {
+    /// Prints the report as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.is_valid() {
+            true => write!(f, "Output {} is valid ('{}')", self.field, self.expected),
+            false => write!(
+                f,
+                "Output {} mismatch - expected '{}', but computed '{}'",
+                self.field, self.expected, self.computed
+            ),
+        }
+    }
+}
+
+impl<N: Network> Output<N> {
+    /// Returns a report of the expected and computed hash of the output, to diagnose a failed `verify`.
+    /// For a record, the checksum is checked. For an external record, there is no value to check,
+    /// so the ID is only checked to be non-zero, as a zero ID indicates a malformed output.
+    pub fn explain_verify(&self, function_id: Field<N>, tcm: &Field<N>, index: usize) -> Result<OutputVerifyReport<N>> {
+        // Computes the output ID as `Hash(function ID || output || tcm || index)`.
+        let hash_with_index = |fields: Vec<Field<N>>| {
+            // Construct the (console) output index as a field element.
+            let index = Field::from_u16(index as u16);
+            // Construct the preimage as `(function ID || output || tcm || index)`.
+            let mut preimage = Vec::with_capacity(fields.len() + 3);
+            preimage.push(function_id);
+            preimage.extend(fields);
+            preimage.push(*tcm);
+            preimage.push(index);
+            N::hash_psd8(&preimage)
+        };
+
+        let (field, expected, computed) = match self {
+            Output::Constant(hash, Some(output)) => ("id", *hash, hash_with_index(output.to_fields()?)?),
+            Output::Public(hash, Some(output)) => ("id", *hash, hash_with_index(output.to_fields()?)?),
+            Output::Private(hash, Some(value)) => ("id", *hash, N::hash_psd8(&value.to_fields()?)?),
+            // Note: The checksum covers the nonce of the record ciphertext, which rejects a record with a swapped nonce.
+            Output::Record(_, checksum, Some(value)) => ("checksum", *checksum, N::hash_bhp1024(&value.to_bits_le())?),
+            Output::Future(hash, Some(output)) => ("id", *hash, hash_with_index(output.to_fields()?)?),
+            Output::Constant(_, None)
+            | Output::Public(_, None)
+            | Output::Private(_, None)
+            | Output::Record(_, _, None)
+            | Output::Future(_, None) => {
+                // This enforces that the transition *must* contain the value for this transition output.
+                // A similar rule is enforced for the transition input.
+                bail!("A transition output value is missing")
+            }
+            Output::ExternalRecord(hash) => {
+                // Ensure the external record ID is non-zero.
+                ensure!(!hash.is_zero(), "The external record ID is zero");
+                ("id", *hash, *hash)
+            }
+        };
+        Ok(OutputVerifyReport { field, expected, computed })
+    }
+}
@@ -128,9 +128,56 @@ impl<'de, N: Network> Deserialize<'de> for Output<N> {
     }
 }
 
+/// A wrapper to serialize a transition output with a view key.
+/// A record output that is owned by the view key is serialized with its decrypted entries, keyed by name.
+/// All other outputs are serialized as they would be without a view key.
+pub struct SerializeWithViewKey<'a, N: Network> {
+    /// The transition output.
+    output: &'a Output<N>,
+    /// The view key.
+    view_key: &'a ViewKey<N>,
+}
+
+impl<N: Network> Output<N> {
+    /// Returns a wrapper to serialize the output, decrypting the record (if any) that is owned by the view key.
+    pub const fn serialize_with_view_key<'a>(&'a self, view_key: &'a ViewKey<N>) -> SerializeWithViewKey<'a, N> {
+        SerializeWithViewKey { output: self, view_key }
+    }
+}
+
+impl<N: Network> Serialize for SerializeWithViewKey<'_, N> {
+    /// Serializes the transition output, decrypting the record if it is owned by the view key.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match (serializer.is_human_readable(), self.output) {
+            (true, Output::Record(id, checksum, Some(record))) if record.is_owner(self.view_key) => {
+                // Decrypt the record.
+                let record = record.decrypt(self.view_key).map_err(ser::Error::custom)?;
+                // Serialize the record entries, keyed by name.
+                let mut entries = serde_json::Map::with_capacity(record.data().len() + 2);
+                entries.insert("owner".to_string(), serde_json::Value::String(record.owner().to_string()));
+                for (name, entry) in record.data() {
+                    entries.insert(name.to_string(), serde_json::Value::String(entry.to_string()));
+                }
+                entries.insert("_nonce".to_string(), serde_json::Value::String(format!("{}.public", record.nonce())));
+
+                let mut output = serializer.serialize_struct("Output", 4)?;
+                output.serialize_field("type", "record")?;
+                output.serialize_field("id", &id)?;
+                output.serialize_field("checksum", &checksum)?;
+                output.serialize_field("value", &entries)?;
+                output.end()
+            }
+            _ => self.output.serialize(serializer),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::{account::PrivateKey, network::MainnetV0, types::Address};
+
+    type CurrentNetwork = MainnetV0;
 
     fn check_serde_json<
         T: Serialize + for<'a> Deserialize<'a> + Debug + Display + PartialEq + Eq + FromStr + ToBytes + FromBytes,
@@ -178,4 +225,50 @@ mod tests {
             check_bincode(expected);
         }
     }
+
+    #[test]
+    fn test_serialize_with_view_key() {
+        let rng = &mut TestRng::default();
+
+        // Sample an account.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let owner = Address::try_from(&private_key).unwrap();
+
+        // Sample a record output.
+        let randomizer = Uniform::rand(rng);
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {owner}.private, balance: 5u64.private, token_amount: 100u64.public, _nonce: {nonce}.public }}"
+        ))
+        .unwrap();
+        let record_ciphertext = record.encrypt(randomizer).unwrap();
+        let record_checksum = CurrentNetwork::hash_bhp1024(&record_ciphertext.to_bits_le()).unwrap();
+        let output = Output::Record(Uniform::rand(rng), record_checksum, Some(record_ciphertext.clone()));
+
+        // Ensure the default serialization contains the record ciphertext.
+        let candidate = serde_json::to_value(&output).unwrap();
+        assert_eq!(candidate["value"], serde_json::Value::String(record_ciphertext.to_string()));
+
+        // Ensure the serialization with the view key contains the decrypted entries, keyed by name.
+        let candidate = serde_json::to_value(output.serialize_with_view_key(&view_key)).unwrap();
+        assert_eq!(candidate["type"], "record");
+        assert_eq!(candidate["id"], serde_json::to_value(output.id()).unwrap());
+        assert_eq!(candidate["checksum"], serde_json::to_value(record_checksum).unwrap());
+        assert_eq!(candidate["value"]["owner"], format!("{owner}.private"));
+        assert_eq!(candidate["value"]["balance"], "5u64.private");
+        assert_eq!(candidate["value"]["token_amount"], "100u64.public");
+        assert_eq!(candidate["value"]["_nonce"], format!("{nonce}.public"));
+
+        // Ensure the serialization with another view key contains the record ciphertext.
+        let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let candidate = serde_json::to_value(output.serialize_with_view_key(&other_view_key)).unwrap();
+        assert_eq!(candidate, serde_json::to_value(&output).unwrap());
+
+        // Ensure the serialization of a non-record output is unchanged.
+        for (_, output) in crate::transition::output::test_helpers::sample_outputs() {
+            let candidate = serde_json::to_string(&output.serialize_with_view_key(&view_key)).unwrap();
+            assert_eq!(candidate, serde_json::to_string(&output).unwrap());
+        }
+    }
 }
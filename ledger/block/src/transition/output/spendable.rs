@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::{
+    account::{GraphKey, PrivateKey},
+    types::Address,
+};
+
+/// A decrypted record output, along with the data that can be precomputed to later spend the record.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpendableRecord<N: Network> {
+    /// The commitment of the record.
+    commitment: Field<N>,
+    /// The decrypted record.
+    record: Record<N, Plaintext<N>>,
+    /// The tag of the record.
+    tag: Field<N>,
+    /// The serial number generator `H` of the record, which is `HashToGroup(commitment)`.
+    h: Group<N>,
+}
+
+impl<N: Network> SpendableRecord<N> {
+    /// Returns the commitment of the record.
+    pub const fn commitment(&self) -> &Field<N> {
+        &self.commitment
+    }
+
+    /// Returns the decrypted record.
+    pub const fn record(&self) -> &Record<N, Plaintext<N>> {
+        &self.record
+    }
+
+    /// Returns the tag of the record.
+    pub const fn tag(&self) -> &Field<N> {
+        &self.tag
+    }
+
+    /// Returns the serial number generator `H` of the record.
+    pub const fn h(&self) -> &Group<N> {
+        &self.h
+    }
+
+    /// Returns the serial number of the record, using the precomputed generator `H`.
+    pub fn serial_number(&self, private_key: &PrivateKey<N>) -> Result<Field<N>> {
+        // Ensure the private key belongs to the owner of the record.
+        let address = Address::try_from(private_key)?;
+        ensure!(*self.record.owner().deref() == address, "The private key does not own the record");
+        // Compute `gamma` as `sk_sig * H`.
+        let gamma = self.h * private_key.sk_sig();
+        // Compute the serial number from `gamma`.
+        Record::<N, Plaintext<N>>::serial_number_from_gamma(&gamma, self.commitment)
+    }
+}
+
+impl<N: Network> Output<N> {
+    /// Decrypts the record output with the given view key, and returns the record along with its spend data.
+    pub fn to_spendable(&self, view_key: &ViewKey<N>) -> Result<SpendableRecord<N>> {
+        // Retrieve the commitment and record ciphertext.
+        let Some((commitment, ciphertext)) = self.record() else {
+            bail!("Expected a record output with a record ciphertext")
+        };
+        // Ensure the view key owns the record.
+        ensure!(ciphertext.is_owner(view_key), "The view key does not own the record");
+        // Decrypt the record.
+        let record = ciphertext.decrypt(view_key)?;
+        // Compute the tag.
+        let tag = Record::<N, Plaintext<N>>::tag(GraphKey::try_from(view_key)?.sk_tag(), *commitment)?;
+        // Compute the generator `H` as `HashToGroup(commitment)`.
+        let h = N::hash_to_group_psd2(&[N::serial_number_domain(), *commitment])?;
+        // Return the spendable record.
+        Ok(SpendableRecord { commitment: *commitment, record, tag, h })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentRecord = Record<CurrentNetwork, Plaintext<CurrentNetwork>>;
+
+    #[test]
+    fn test_to_spendable() {
+        let rng = &mut TestRng::default();
+
+        // Sample an account.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let owner = Address::try_from(&private_key).unwrap();
+
+        // Sample a record output.
+        let randomizer = Uniform::rand(rng);
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let record = CurrentRecord::from_str(&format!(
+            "{{ owner: {owner}.private, token_amount: 100u64.private, _nonce: {nonce}.public }}"
+        ))
+        .unwrap();
+        let ciphertext = record.encrypt(randomizer).unwrap();
+        let checksum = CurrentNetwork::hash_bhp1024(&ciphertext.to_bits_le()).unwrap();
+        let commitment = Uniform::rand(rng);
+        let output = Output::Record(commitment, checksum, Some(ciphertext));
+
+        // Ensure the spendable record is produced.
+        let spendable = output.to_spendable(&view_key).unwrap();
+        assert_eq!(spendable.commitment(), &commitment);
+        assert_eq!(spendable.record(), &record);
+
+        // Ensure the spend data matches the direct derivations.
+        let sk_tag = GraphKey::try_from(&view_key).unwrap().sk_tag();
+        assert_eq!(spendable.tag(), &CurrentRecord::tag(sk_tag, commitment).unwrap());
+        let expected = CurrentRecord::serial_number(private_key, commitment).unwrap();
+        assert_eq!(spendable.serial_number(&private_key).unwrap(), expected);
+
+        // Ensure another account can not produce a spendable record.
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        assert!(output.to_spendable(&ViewKey::try_from(&other_private_key).unwrap()).is_err());
+        assert!(spendable.serial_number(&other_private_key).is_err());
+
+        // Ensure a non-record output is rejected.
+        assert!(Output::<CurrentNetwork>::Public(Uniform::rand(rng), None).to_spendable(&view_key).is_err());
+    }
+}
@@ -0,0 +1,244 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The committed data of a transition output, which is the variant, ID, checksum, and nonce of the output.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OutputHeader<N: Network> {
+    /// The variant of the output.
+    variant: Variant,
+    /// The ID of the output.
+    id: Field<N>,
+    /// The checksum of the output, if the output is a record.
+    checksum: Option<Field<N>>,
+    /// The nonce of the record, if the output is a record with a record ciphertext.
+    nonce: Option<Group<N>>,
+}
+
+impl<N: Network> OutputHeader<N> {
+    /// Returns the variant of the output.
+    pub const fn variant(&self) -> Variant {
+        self.variant
+    }
+
+    /// Returns the ID of the output.
+    pub const fn id(&self) -> &Field<N> {
+        &self.id
+    }
+
+    /// Returns the checksum of the output, if the output is a record.
+    pub const fn checksum(&self) -> Option<&Field<N>> {
+        self.checksum.as_ref()
+    }
+
+    /// Returns the nonce of the record, if the output is a record with a record ciphertext.
+    pub const fn nonce(&self) -> Option<&Group<N>> {
+        self.nonce.as_ref()
+    }
+}
+
+/// The witness data of a transition output, which is the optional payload of the output.
+#[derive(Clone, PartialEq, Eq)]
+pub enum OutputPayload<N: Network> {
+    /// The plaintext of a constant or public output.
+    Plaintext(Plaintext<N>),
+    /// The ciphertext of a private output.
+    Ciphertext(Ciphertext<N>),
+    /// The record ciphertext of a record output.
+    Record(Record<N, Ciphertext<N>>),
+    /// The future of a future output.
+    Future(Future<N>),
+}
+
+impl<N: Network> Output<N> {
+    /// Splits the output into its committed data (the header) and its witness data (the payload), if any.
+    pub fn split(self) -> (OutputHeader<N>, Option<OutputPayload<N>>) {
+        let variant = self.variant();
+        let checksum = self.checksum().copied();
+        let (id, nonce, payload) = match self {
+            Output::Constant(id, plaintext) | Output::Public(id, plaintext) => {
+                (id, None, plaintext.map(OutputPayload::Plaintext))
+            }
+            Output::Private(id, ciphertext) => (id, None, ciphertext.map(OutputPayload::Ciphertext)),
+            Output::Record(commitment, _, record) => {
+                (commitment, record.as_ref().map(|record| *record.nonce()), record.map(OutputPayload::Record))
+            }
+            Output::ExternalRecord(id) => (id, None, None),
+            Output::Future(id, future) => (id, None, future.map(OutputPayload::Future)),
+        };
+        (OutputHeader { variant, id, checksum, nonce }, payload)
+    }
+
+    /// Reconstructs the output from its committed data (the header) and its witness data (the payload), if any.
+    /// Note: This does not verify the payload against the header ID, use `Output::verify` to do so.
+    pub fn join(header: OutputHeader<N>, payload: Option<OutputPayload<N>>) -> Result<Self> {
+        // Ensure the checksum is only present for a record output.
+        ensure!(
+            header.checksum.is_some() == (header.variant == RECORD_VARIANT),
+            "Mismatched checksum in the output header"
+        );
+        // Ensure the nonce is only present for a record output with a record ciphertext.
+        match (&header.nonce, &payload) {
+            (Some(nonce), Some(OutputPayload::Record(record))) => {
+                ensure!(nonce == record.nonce(), "Mismatched record nonce in the output header")
+            }
+            (None, Some(OutputPayload::Record(_))) => bail!("Missing record nonce in the output header"),
+            (Some(_), _) => bail!("Unexpected record nonce in the output header"),
+            (None, _) => (),
+        }
+
+        let id = header.id;
+        let output = match (header.variant, header.checksum, payload) {
+            (CONSTANT_VARIANT, _, None) => Output::Constant(id, None),
+            (CONSTANT_VARIANT, _, Some(OutputPayload::Plaintext(plaintext))) => Output::Constant(id, Some(plaintext)),
+            (PUBLIC_VARIANT, _, None) => Output::Public(id, None),
+            (PUBLIC_VARIANT, _, Some(OutputPayload::Plaintext(plaintext))) => Output::Public(id, Some(plaintext)),
+            (PRIVATE_VARIANT, _, None) => Output::Private(id, None),
+            (PRIVATE_VARIANT, _, Some(OutputPayload::Ciphertext(ciphertext))) => Output::Private(id, Some(ciphertext)),
+            (RECORD_VARIANT, Some(checksum), None) => Output::Record(id, checksum, None),
+            (RECORD_VARIANT, Some(checksum), Some(OutputPayload::Record(record))) => {
+                Output::Record(id, checksum, Some(record))
+            }
+            (EXTERNAL_RECORD_VARIANT, _, None) => Output::ExternalRecord(id),
+            (FUTURE_VARIANT, _, None) => Output::Future(id, None),
+            (FUTURE_VARIANT, _, Some(OutputPayload::Future(future))) => Output::Future(id, Some(future)),
+            (variant, ..) if variant < NUM_VARIANTS => bail!("Mismatched payload for output variant {variant}"),
+            (variant, ..) => bail!("Invalid output variant {variant}"),
+        };
+        Ok(output)
+    }
+}
+
+impl<N: Network> FromBytes for OutputHeader<N> {
+    /// Reads the output header from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let variant = Variant::read_le(&mut reader)?;
+        if variant >= NUM_VARIANTS {
+            return Err(error(format!("Failed to decode output variant {variant}")));
+        }
+        let id = FromBytes::read_le(&mut reader)?;
+        let checksum = match bool::read_le(&mut reader)? {
+            true => Some(FromBytes::read_le(&mut reader)?),
+            false => None,
+        };
+        let nonce = match bool::read_le(&mut reader)? {
+            true => Some(FromBytes::read_le(&mut reader)?),
+            false => None,
+        };
+        Ok(Self { variant, id, checksum, nonce })
+    }
+}
+
+impl<N: Network> ToBytes for OutputHeader<N> {
+    /// Writes the output header to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.variant.write_le(&mut writer)?;
+        self.id.write_le(&mut writer)?;
+        match &self.checksum {
+            Some(checksum) => {
+                true.write_le(&mut writer)?;
+                checksum.write_le(&mut writer)?;
+            }
+            None => false.write_le(&mut writer)?,
+        }
+        match &self.nonce {
+            Some(nonce) => {
+                true.write_le(&mut writer)?;
+                nonce.write_le(&mut writer)
+            }
+            None => false.write_le(&mut writer),
+        }
+    }
+}
+
+impl<N: Network> FromBytes for OutputPayload<N> {
+    /// Reads the output payload from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let index = Variant::read_le(&mut reader)?;
+        let payload = match index {
+            0 => Self::Plaintext(FromBytes::read_le(&mut reader)?),
+            1 => Self::Ciphertext(FromBytes::read_le(&mut reader)?),
+            2 => Self::Record(FromBytes::read_le(&mut reader)?),
+            3 => Self::Future(FromBytes::read_le(&mut reader)?),
+            4.. => return Err(error(format!("Failed to decode output payload variant {index}"))),
+        };
+        Ok(payload)
+    }
+}
+
+impl<N: Network> ToBytes for OutputPayload<N> {
+    /// Writes the output payload to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::Plaintext(plaintext) => {
+                (0 as Variant).write_le(&mut writer)?;
+                plaintext.write_le(&mut writer)
+            }
+            Self::Ciphertext(ciphertext) => {
+                (1 as Variant).write_le(&mut writer)?;
+                ciphertext.write_le(&mut writer)
+            }
+            Self::Record(record) => {
+                (2 as Variant).write_le(&mut writer)?;
+                record.write_le(&mut writer)
+            }
+            Self::Future(future) => {
+                (3 as Variant).write_le(&mut writer)?;
+                future.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_join() {
+        for (_, expected) in crate::transition::output::test_helpers::sample_outputs() {
+            // Split the output.
+            let (header, payload) = expected.clone().split();
+            assert_eq!(header.variant(), expected.variant());
+            assert_eq!(header.id(), expected.id());
+            assert_eq!(header.checksum(), expected.checksum());
+            assert_eq!(header.nonce(), expected.record().map(|(_, record)| record.nonce()));
+
+            // Ensure the header and payload round-trip through bytes.
+            let header = OutputHeader::read_le(&header.to_bytes_le().unwrap()[..]).unwrap();
+            let payload = payload.map(|payload| OutputPayload::read_le(&payload.to_bytes_le().unwrap()[..]).unwrap());
+
+            // Ensure the output is reconstructed.
+            assert_eq!(expected, Output::join(header, payload).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_join_rejects_mismatched_payload() {
+        let outputs = crate::transition::output::test_helpers::sample_outputs();
+
+        // Split a public output and a record output.
+        let (public_header, public_payload) = outputs[4].1.clone().split();
+        let (record_header, record_payload) = outputs[10].1.clone().split();
+        assert!(matches!(public_payload, Some(OutputPayload::Plaintext(_))));
+        assert!(matches!(record_payload, Some(OutputPayload::Record(_))));
+
+        // Ensure the payloads can not be swapped.
+        assert!(Output::join(public_header, record_payload.clone()).is_err());
+        assert!(Output::join(record_header, public_payload).is_err());
+        // Ensure the record payload requires the record nonce.
+        assert!(Output::join(OutputHeader { nonce: None, ..record_header }, record_payload).is_err());
+    }
+}
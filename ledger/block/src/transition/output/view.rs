@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A human-readable view of a transition output.
+/// The value is included if the output is public, or if it is a record that is decrypted by the given view key.
+#[derive(Clone, PartialEq, Eq)]
+pub struct OutputView<N: Network> {
+    /// The type of the output.
+    type_name: &'static str,
+    /// The ID of the output.
+    id: Field<N>,
+    /// The checksum of the output, if the output is a record.
+    checksum: Option<Field<N>>,
+    /// The value of the output, if it is visible.
+    value: Option<Value<N>>,
+}
+
+impl<N: Network> OutputView<N> {
+    /// Returns the type of the output.
+    pub const fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns the ID of the output.
+    pub const fn id(&self) -> &Field<N> {
+        &self.id
+    }
+
+    /// Returns the checksum of the output, if the output is a record.
+    pub const fn checksum(&self) -> Option<&Field<N>> {
+        self.checksum.as_ref()
+    }
+
+    /// Returns the value of the output, if it is visible.
+    pub const fn value(&self) -> Option<&Value<N>> {
+        self.value.as_ref()
+    }
+}
+
+impl<N: Network> Output<N> {
+    /// Returns a human-readable view of the output.
+    /// If a view key is given, record outputs owned by the view key are decrypted.
+    /// Note: Private outputs are never decrypted, as they are encrypted under the transition view key.
+    pub fn to_view(&self, view_key: Option<&ViewKey<N>>) -> OutputView<N> {
+        let (type_name, checksum, value) = match self {
+            Output::Constant(_, value) => ("constant", None, value.clone().map(Value::Plaintext)),
+            Output::Public(_, value) => ("public", None, value.clone().map(Value::Plaintext)),
+            Output::Private(..) => ("private", None, None),
+            Output::Record(_, checksum, record) => {
+                // Decrypt the record, if it is owned by the view key.
+                let value = match (record, view_key) {
+                    (Some(record), Some(view_key)) if record.is_owner(view_key) => {
+                        record.decrypt(view_key).ok().map(Value::Record)
+                    }
+                    _ => None,
+                };
+                ("record", Some(*checksum), value)
+            }
+            Output::ExternalRecord(..) => ("external_record", None, None),
+            Output::Future(_, value) => ("future", None, value.clone().map(Value::Future)),
+        };
+        OutputView { type_name, id: *self.id(), checksum, value }
+    }
+}
+
+impl<N: Network> Serialize for OutputView<N> {
+    /// Serializes the output view into a JSON object.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut view = serializer
+            .serialize_struct("OutputView", 2 + self.checksum.is_some() as usize + self.value.is_some() as usize)?;
+        view.serialize_field("type", self.type_name)?;
+        view.serialize_field("id", &self.id)?;
+        if let Some(checksum) = &self.checksum {
+            view.serialize_field("checksum", checksum)?;
+        }
+        if let Some(value) = &self.value {
+            view.serialize_field("value", value)?;
+        }
+        view.end()
+    }
+}
+
+impl<N: Network> Display for OutputView<N> {
+    /// Displays the output view as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::MainnetV0, types::Address};
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Returns a record output owned by the given address, with its plaintext.
+    fn sample_record_output(
+        owner: Address<CurrentNetwork>,
+        rng: &mut TestRng,
+    ) -> (Output<CurrentNetwork>, Record<CurrentNetwork, Plaintext<CurrentNetwork>>) {
+        let randomizer = Uniform::rand(rng);
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {owner}.private, token_amount: 100u64.private, _nonce: {nonce}.public }}"
+        ))
+        .unwrap();
+        let ciphertext = record.encrypt(randomizer).unwrap();
+        let checksum = CurrentNetwork::hash_bhp1024(&ciphertext.to_bits_le()).unwrap();
+        (Output::Record(Uniform::rand(rng), checksum, Some(ciphertext)), record)
+    }
+
+    #[test]
+    fn test_to_view_sample_outputs() {
+        for (_, output) in crate::transition::output::test_helpers::sample_outputs() {
+            let view = output.to_view(None);
+            // Ensure the ID and checksum are always shown.
+            assert_eq!(view.id(), output.id());
+            assert_eq!(view.checksum(), output.checksum());
+            // Ensure only public values are shown.
+            match &output {
+                Output::Constant(_, value) | Output::Public(_, value) => {
+                    assert_eq!(view.value(), value.clone().map(Value::Plaintext).as_ref())
+                }
+                Output::Future(_, value) => assert_eq!(view.value(), value.clone().map(Value::Future).as_ref()),
+                Output::Private(..) | Output::Record(..) | Output::ExternalRecord(..) => {
+                    assert!(view.value().is_none())
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_view_without_view_key() {
+        let rng = &mut TestRng::default();
+
+        // Ensure a record output is not decrypted without a view key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let (output, _) = sample_record_output(Address::try_from(&private_key).unwrap(), rng);
+        let view = output.to_view(None);
+        assert_eq!(view.type_name(), "record");
+        assert!(view.value().is_none());
+        assert_eq!(
+            view.to_string(),
+            format!(r#"{{"type":"record","id":"{}","checksum":"{}"}}"#, output.id(), output.checksum().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_to_view_with_view_key() {
+        let rng = &mut TestRng::default();
+
+        // Sample an account.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let (output, record) = sample_record_output(Address::try_from(&private_key).unwrap(), rng);
+
+        // Ensure the record is decrypted by the owner.
+        let view = output.to_view(Some(&view_key));
+        assert_eq!(view.type_name(), "record");
+        assert_eq!(view.id(), output.id());
+        assert_eq!(view.checksum(), output.checksum());
+        assert_eq!(view.value(), Some(&Value::Record(record)));
+
+        // Ensure the record is not decrypted by another view key.
+        let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        assert!(output.to_view(Some(&other_view_key)).value().is_none());
+
+        // Ensure a public output is shown regardless of the view key.
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("5u64").unwrap();
+        let output = Output::Public(Uniform::rand(rng), Some(plaintext.clone()));
+        let view = output.to_view(Some(&view_key));
+        assert_eq!(view.type_name(), "public");
+        assert_eq!(view.value(), Some(&Value::Plaintext(plaintext)));
+        assert_eq!(view.to_string(), format!(r#"{{"type":"public","id":"{}","value":"5u64"}}"#, output.id()));
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A compact summary of a transition, which keeps only the IDs of its inputs and outputs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransitionSummary<N: Network> {
+    /// The transition ID.
+    id: N::TransitionID,
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The input IDs.
+    input_ids: Vec<Field<N>>,
+    /// The output IDs.
+    output_ids: Vec<Field<N>>,
+}
+
+impl<N: Network> TransitionSummary<N> {
+    /// Returns the transition ID.
+    pub const fn id(&self) -> &N::TransitionID {
+        &self.id
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the input IDs.
+    pub fn input_ids(&self) -> &[Field<N>] {
+        &self.input_ids
+    }
+
+    /// Returns the output IDs.
+    pub fn output_ids(&self) -> &[Field<N>] {
+        &self.output_ids
+    }
+}
+
+impl<N: Network> From<&Transition<N>> for TransitionSummary<N> {
+    /// Returns the summary of the given transition.
+    fn from(transition: &Transition<N>) -> Self {
+        Self {
+            id: *transition.id(),
+            program_id: *transition.program_id(),
+            function_name: *transition.function_name(),
+            input_ids: transition.inputs().iter().map(|input| *input.id()).collect(),
+            output_ids: transition.outputs().iter().map(|output| *output.id()).collect(),
+        }
+    }
+}
+
+impl<N: Network> Display for TransitionSummary<N> {
+    /// Prints the transition summary as `program_id/function_name (id): inputs -> outputs`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}/{} ({}): {} inputs -> {} outputs",
+            self.program_id,
+            self.function_name,
+            self.id,
+            self.input_ids.len(),
+            self.output_ids.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transition_summary() {
+        let rng = &mut TestRng::default();
+
+        // Sample a transition.
+        let transition = crate::transition::test_helpers::sample_transition(rng);
+
+        // Ensure the summary retains the IDs.
+        let summary = TransitionSummary::from(&transition);
+        assert_eq!(summary.id(), transition.id());
+        assert_eq!(summary.input_ids(), transition.input_ids().copied().collect::<Vec<_>>());
+        assert_eq!(summary.output_ids(), transition.output_ids().copied().collect::<Vec<_>>());
+
+        // Ensure the summaries of equal transitions are equal.
+        assert_eq!(summary, TransitionSummary::from(&transition.clone()));
+
+        // Ensure the summaries of different transitions are not equal.
+        let other = crate::transition::test_helpers::sample_transition(rng);
+        assert_ne!(summary, TransitionSummary::from(&other));
+    }
+}
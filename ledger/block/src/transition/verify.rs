@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::types::U16;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// Ensures the outputs of all of the given transitions are well-formed, by hashing them in parallel.
+/// The function ID of each transition is computed once, and the outputs of every transition are
+/// checked as a single batch. On failure, the first invalid output (in transition order) is returned.
+pub fn verify_block_outputs<N: Network>(transitions: &[Transition<N>]) -> Result<()> {
+    // Compute the function ID of each transition.
+    let function_ids = cfg_iter!(transitions)
+        .map(|transition| compute_function_id(&U16::new(N::ID), transition.program_id(), transition.function_name()))
+        .collect::<Result<Vec<_>>>()?;
+
+    // Collect the outputs of every transition, as a tuple of `(transition, function ID, output index, output)`.
+    let outputs = transitions
+        .iter()
+        .zip_eq(&function_ids)
+        .flat_map(|(transition, function_id)| {
+            transition.outputs().iter().enumerate().map(move |(index, output)| (transition, function_id, index, output))
+        })
+        .collect::<Vec<_>>();
+
+    // Check each output against its ID (or checksum).
+    let results = cfg_iter!(outputs)
+        .map(|(transition, function_id, index, output)| {
+            let num_inputs = transition.inputs().len();
+            match output.explain_verify(**function_id, transition.tcm(), num_inputs + index) {
+                Ok(report) if report.is_valid() => Ok(()),
+                Ok(report) => Err(anyhow!("{report}")),
+                Err(error) => Err(error),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // Return the first failure, with the transition and output that caused it.
+    for ((transition, _, index, _), result) in outputs.iter().zip_eq(results) {
+        if let Err(error) = result {
+            bail!(
+                "Output {index} of transition '{}' ('{}/{}') is invalid - {error}",
+                transition.id(),
+                transition.program_id(),
+                transition.function_name()
+            )
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_block_outputs() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transitions.
+        let transitions = (0..3).map(|_| crate::transition::test_helpers::sample_transition(rng)).collect::<Vec<_>>();
+        assert!(verify_block_outputs(&transitions).is_ok());
+        assert!(verify_block_outputs::<console::network::MainnetV0>(&[]).is_ok());
+
+        // Corrupt the first output of the second transition, by replacing its value.
+        let transition = &transitions[1];
+        let mut outputs = transition.outputs().to_vec();
+        outputs[0] = Output::Constant(*outputs[0].id(), Some(Plaintext::from_str("1field").unwrap()));
+        let corrupt = Transition::new(
+            *transition.program_id(),
+            *transition.function_name(),
+            transition.inputs().to_vec(),
+            outputs,
+            *transition.tpk(),
+            *transition.tcm(),
+            *transition.scm(),
+        )
+        .unwrap();
+        let transitions = vec![transitions[0].clone(), corrupt.clone(), transitions[2].clone()];
+
+        // Ensure the error pinpoints the corrupt output.
+        let error = verify_block_outputs(&transitions).unwrap_err().to_string();
+        assert!(error.starts_with(&format!("Output 0 of transition '{}'", corrupt.id())), "{error}");
+        assert!(error.contains("mismatch"), "{error}");
+    }
+}
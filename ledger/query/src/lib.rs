@@ -23,5 +23,8 @@ mod query;
 #[cfg(feature = "query")]
 pub use query::*;
 
+mod pinned;
+pub use pinned::*;
+
 mod traits;
 pub use traits::*;
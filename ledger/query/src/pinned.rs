@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::QueryTrait;
+use console::{network::prelude::*, program::StatePath, types::Field};
+
+/// A query that fetches the current state root from the underlying query once,
+/// and returns it for every subsequent call to `current_state_root`.
+#[derive(Clone)]
+pub struct PinnedQuery<N: Network, Q: QueryTrait<N>> {
+    /// The underlying query.
+    query: Q,
+    /// The pinned state root.
+    state_root: N::StateRoot,
+}
+
+impl<N: Network, Q: QueryTrait<N>> PinnedQuery<N, Q> {
+    /// Initializes a new pinned query, by fetching the current state root from the given query.
+    pub fn new(query: Q) -> Result<Self> {
+        let state_root = query.current_state_root()?;
+        Ok(Self { query, state_root })
+    }
+
+    /// Initializes a new pinned query, by fetching the current state root from the given query.
+    #[cfg(feature = "async")]
+    pub async fn new_async(query: Q) -> Result<Self> {
+        let state_root = query.current_state_root_async().await?;
+        Ok(Self { query, state_root })
+    }
+
+    /// Returns the pinned state root.
+    pub const fn state_root(&self) -> &N::StateRoot {
+        &self.state_root
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait(?Send))]
+impl<N: Network, Q: QueryTrait<N>> QueryTrait<N> for PinnedQuery<N, Q> {
+    /// Returns the pinned state root.
+    fn current_state_root(&self) -> Result<N::StateRoot> {
+        Ok(self.state_root)
+    }
+
+    /// Returns the pinned state root.
+    #[cfg(feature = "async")]
+    async fn current_state_root_async(&self) -> Result<N::StateRoot> {
+        Ok(self.state_root)
+    }
+
+    /// Returns a state path for the given `commitment`.
+    fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        let state_path = self.query.get_state_path_for_commitment(commitment)?;
+        // Ensure the state path is anchored to the pinned state root.
+        ensure!(
+            state_path.global_state_root() == self.state_root,
+            "The state path for '{commitment}' does not match the pinned state root"
+        );
+        Ok(state_path)
+    }
+
+    /// Returns a state path for the given `commitment`.
+    #[cfg(feature = "async")]
+    async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        let state_path = self.query.get_state_path_for_commitment_async(commitment).await?;
+        // Ensure the state path is anchored to the pinned state root.
+        ensure!(
+            state_path.global_state_root() == self.state_root,
+            "The state path for '{commitment}' does not match the pinned state root"
+        );
+        Ok(state_path)
+    }
+}
@@ -0,0 +1,242 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ledger_block::Output;
+
+/// An input or output parameter of a function ABI.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AbiParameter<N: Network> {
+    /// The name of the parameter, i.e. the register or operand.
+    name: String,
+    /// The value type of the parameter.
+    value_type: ValueType<N>,
+}
+
+impl<N: Network> AbiParameter<N> {
+    /// Returns the name of the parameter, i.e. the register or operand.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the value type of the parameter.
+    pub const fn value_type(&self) -> &ValueType<N> {
+        &self.value_type
+    }
+
+    /// Returns the type name of the parameter, without its visibility, i.e. `field` or `token`.
+    pub fn type_name(&self) -> String {
+        match &self.value_type {
+            ValueType::Constant(plaintext_type)
+            | ValueType::Public(plaintext_type)
+            | ValueType::Private(plaintext_type) => plaintext_type.to_string(),
+            ValueType::Record(record_name) => record_name.to_string(),
+            ValueType::ExternalRecord(locator) | ValueType::Future(locator) => locator.to_string(),
+        }
+    }
+
+    /// Returns the visibility of the parameter, i.e. `public` or `record`.
+    pub const fn visibility(&self) -> &'static str {
+        match &self.value_type {
+            ValueType::Constant(..) => "constant",
+            ValueType::Public(..) => "public",
+            ValueType::Private(..) => "private",
+            ValueType::Record(..) => "record",
+            ValueType::ExternalRecord(..) => "external_record",
+            ValueType::Future(..) => "future",
+        }
+    }
+}
+
+impl<N: Network> Serialize for AbiParameter<N> {
+    /// Serializes the parameter into a JSON object.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut parameter = serializer.serialize_struct("AbiParameter", 3)?;
+        parameter.serialize_field("name", &self.name)?;
+        parameter.serialize_field("type", &self.type_name())?;
+        parameter.serialize_field("visibility", self.visibility())?;
+        parameter.end()
+    }
+}
+
+/// The ABI of a program function, which describes its inputs and outputs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionAbi<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The inputs of the function.
+    inputs: Vec<AbiParameter<N>>,
+    /// The outputs of the function.
+    outputs: Vec<AbiParameter<N>>,
+}
+
+impl<N: Network> FunctionAbi<N> {
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the inputs of the function.
+    pub fn inputs(&self) -> &[AbiParameter<N>] {
+        &self.inputs
+    }
+
+    /// Returns the outputs of the function.
+    pub fn outputs(&self) -> &[AbiParameter<N>] {
+        &self.outputs
+    }
+}
+
+impl<N: Network> Serialize for FunctionAbi<N> {
+    /// Serializes the function ABI into a JSON object.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut abi = serializer.serialize_struct("FunctionAbi", 4)?;
+        abi.serialize_field("program", &self.program_id)?;
+        abi.serialize_field("function", &self.function_name)?;
+        abi.serialize_field("inputs", &self.inputs)?;
+        abi.serialize_field("outputs", &self.outputs)?;
+        abi.end()
+    }
+}
+
+impl<N: Network> Display for FunctionAbi<N> {
+    /// Displays the function ABI as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?)
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns the ABI of the given function, which describes its inputs and outputs.
+    pub fn function_abi(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+    ) -> Result<FunctionAbi<N>> {
+        // Retrieve the program.
+        let program = self.get_program(program_id)?;
+        // Prepare the function name.
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+        // Retrieve the function.
+        let function = program.get_function_ref(&function_name)?;
+
+        // Describe the inputs and outputs.
+        let inputs = function
+            .inputs()
+            .iter()
+            .map(|input| AbiParameter { name: input.register().to_string(), value_type: input.value_type().clone() })
+            .collect();
+        let outputs = function
+            .outputs()
+            .iter()
+            .map(|output| AbiParameter { name: output.operand().to_string(), value_type: output.value_type().clone() })
+            .collect();
+
+        Ok(FunctionAbi { program_id: *program.id(), function_name, inputs, outputs })
+    }
+
+    /// Returns the program ID that produced the external record output at the given index of the transition.
+    /// Note: The output only holds the output commitment, so the program ID is read from the output type
+    /// that the function declares, i.e. `token.aleo` for `output r0 as token.aleo/token.record`.
+    pub fn external_record_program_id(&self, transition: &Transition<N>, index: usize) -> Result<ProgramID<N>> {
+        // Ensure the output is an external record.
+        match transition.outputs().get(index) {
+            Some(Output::ExternalRecord(..)) => (),
+            Some(_) => bail!("Output {index} of transition '{}' is not an external record", transition.id()),
+            None => bail!("Transition '{}' does not have output {index}", transition.id()),
+        }
+        // Retrieve the function.
+        let function = self.get_stack(transition.program_id())?.get_function(transition.function_name())?;
+        // Retrieve the program ID from the declared output type.
+        match function.output_types().get(index) {
+            Some(ValueType::ExternalRecord(locator)) => Ok(*locator.program_id()),
+            _ => bail!(
+                "Output {index} of '{}/{}' is not declared as an external record",
+                transition.program_id(),
+                transition.function_name()
+            ),
+        }
+    }
+
+    /// Returns the selector of the given function, which is the first 4 bytes (in little-endian) of
+    /// `Hash("{function_name}({input types})")`, e.g. the hash of `compute(field.private,field.public)`.
+    pub fn function_selector(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+    ) -> Result<u32> {
+        // Prepare the function name.
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+        // Retrieve the function.
+        let function = self.get_program(program_id)?.get_function_ref(&function_name)?;
+        // Compute the selector.
+        Self::compute_selector(&function_name, &function.input_types())
+    }
+
+    /// Returns the name of the function with the given selector.
+    pub fn function_by_selector(&self, program_id: impl TryInto<ProgramID<N>>, selector: u32) -> Result<Identifier<N>> {
+        // Retrieve the program.
+        let program = self.get_program(program_id)?;
+        // Find the functions with the given selector.
+        let mut candidates = Vec::new();
+        for (function_name, function) in program.functions() {
+            if Self::compute_selector(function_name, &function.input_types())? == selector {
+                candidates.push(*function_name);
+            }
+        }
+        match candidates.as_slice() {
+            [function_name] => Ok(*function_name),
+            [] => bail!("Program '{}' does not contain a function with selector {selector:#010x}", program.id()),
+            _ => bail!("Program '{}' contains several functions with selector {selector:#010x}", program.id()),
+        }
+    }
+
+    /// Authorizes and executes the function with the given selector, on the given inputs.
+    pub fn execute_by_selector<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: impl TryInto<ProgramID<N>>,
+        selector: u32,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Trace<N>)> {
+        // Prepare the program ID.
+        let program_id = program_id.try_into().map_err(|_| anyhow!("Invalid program ID"))?;
+        // Retrieve the function name.
+        let function_name = self.function_by_selector(program_id, selector)?;
+        // Authorize the function call.
+        let authorization = self.authorize::<A, R>(private_key, program_id, function_name, inputs, rng)?;
+        // Execute the authorization.
+        self.execute::<A, R>(authorization, rng)
+    }
+
+    /// Returns the selector for the given function name and input types.
+    fn compute_selector(function_name: &Identifier<N>, input_types: &[ValueType<N>]) -> Result<u32> {
+        // Construct the signature as `{function_name}({input types})`.
+        let signature = format!("{function_name}({})", input_types.iter().join(","));
+        // Hash the signature.
+        let hash = N::hash_bhp256(&signature.as_bytes().to_bits_le())?;
+        // Return the first 4 bytes of the hash.
+        let bytes = hash.to_bytes_le()?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+}
@@ -117,6 +117,8 @@ fn ensure_record_microcredits_is_sufficient<N: Network>(
         Ok(console::program::Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => *amount,
         _ => bail!("The fee record does not contain a 'microcredits' entry"),
     };
+    // Ensure the record is not empty, as it would be spent without paying any fee.
+    ensure!(balance > 0, "Fee record has zero balance");
     // Ensure the balance is sufficient to pay the fee.
     ensure!(balance >= fee_in_microcredits, "Credits record balance is insufficient to pay the fee");
     Ok(())
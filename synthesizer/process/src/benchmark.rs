@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::time::{Duration, Instant};
+
+/// The timings of repeated executions of an authorization, in the order they were measured.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecuteBenchmark {
+    /// The duration of each execution.
+    samples: Vec<Duration>,
+}
+
+impl ExecuteBenchmark {
+    /// Returns the duration of each execution.
+    pub fn samples(&self) -> &[Duration] {
+        &self.samples
+    }
+
+    /// Returns the shortest duration.
+    pub fn min(&self) -> Duration {
+        self.samples.iter().min().copied().unwrap_or_default()
+    }
+
+    /// Returns the median duration.
+    pub fn median(&self) -> Duration {
+        let mut samples = self.samples.clone();
+        samples.sort_unstable();
+        samples.get(samples.len() / 2).copied().unwrap_or_default()
+    }
+
+    /// Returns the longest duration.
+    pub fn max(&self) -> Duration {
+        self.samples.iter().max().copied().unwrap_or_default()
+    }
+}
+
+impl Display for ExecuteBenchmark {
+    /// Prints the benchmark summary.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} samples (min: {:?}, median: {:?}, max: {:?})",
+            self.samples.len(),
+            self.min(),
+            self.median(),
+            self.max()
+        )
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Executes the given authorization `iterations` times, and returns the duration of each execution.
+    /// Note: The proving keys of every function in the authorization are synthesized beforehand,
+    /// and the circuit is reset before each iteration, so the samples only measure the synthesis.
+    pub fn benchmark_execute<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        &self,
+        authorization: &Authorization<N>,
+        iterations: usize,
+        rng: &mut R,
+    ) -> Result<ExecuteBenchmark> {
+        ensure!(iterations > 0, "The number of benchmark iterations must be greater than zero");
+
+        // Synthesize the proving keys, to exclude the setup from the samples.
+        for request in authorization.to_vec_deque() {
+            self.synthesize_key::<A, R>(request.program_id(), request.function_name(), rng)?;
+        }
+
+        // Execute the authorization for each iteration.
+        let mut samples = Vec::with_capacity(iterations);
+        for _ in 0..iterations {
+            // Reset the circuit.
+            A::reset();
+            // Time the execution.
+            let timer = Instant::now();
+            self.execute::<A, R>(authorization.replicate(), rng)?;
+            samples.push(timer.elapsed());
+        }
+        Ok(ExecuteBenchmark { samples })
+    }
+}
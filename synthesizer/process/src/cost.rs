@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{Process, Stack, StackProgramTypes};
+use crate::{CallMetrics, Process, Stack, StackProgramTypes};
 
 use console::{
     prelude::*,
@@ -76,6 +76,49 @@ pub fn execution_cost<N: Network>(process: &Process<N>, execution: &Execution<N>
     Ok((total_cost, (storage_cost, finalize_cost)))
 }
 
+/// The rates to compute a fee from the metrics of an execution.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FeeRates {
+    /// The cost in microcredits of each constraint.
+    pub microcredits_per_constraint: u64,
+}
+
+impl<N: Network> Process<N> {
+    /// Returns the fee in microcredits for an execution with the given call metrics and size in bytes
+    /// (storage cost + finalize cost + constraint cost), where the constraint cost is the total number of
+    /// constraints of the calls, multiplied by the rate per constraint.
+    /// Note: The call metrics are in the order of the trace, so the top-level call is last.
+    pub fn required_fee(&self, metrics: &[CallMetrics<N>], size_in_bytes: u64, rates: FeeRates) -> Result<u64> {
+        // Retrieve the top-level call.
+        let Some(root) = metrics.last() else { bail!("Expected at least one call to compute the required fee") };
+
+        // Compute the storage cost in microcredits.
+        let storage_cost = execution_storage_cost::<N>(size_in_bytes);
+        // Get the finalize cost for the top-level call.
+        let finalize_cost = self.get_stack(root.program_id)?.get_finalize_cost(&root.function_name)?;
+
+        // Compute the total number of constraints of the calls.
+        let num_constraints = metrics
+            .iter()
+            .try_fold(0u64, |acc, metrics| {
+                acc.checked_add(metrics.num_request_constraints)?
+                    .checked_add(metrics.num_function_constraints)?
+                    .checked_add(metrics.num_response_constraints)
+            })
+            .ok_or(anyhow!("The number of constraints overflowed for an execution"))?;
+        // Compute the constraint cost in microcredits.
+        let constraint_cost = num_constraints
+            .checked_mul(rates.microcredits_per_constraint)
+            .ok_or(anyhow!("The constraint cost computation overflowed for an execution"))?;
+
+        // Compute the total cost in microcredits.
+        storage_cost
+            .checked_add(finalize_cost)
+            .and_then(|x| x.checked_add(constraint_cost))
+            .ok_or(anyhow!("The required fee computation overflowed for an execution"))
+    }
+}
+
 /// Returns the storage cost in microcredits for a program execution.
 fn execution_storage_cost<N: Network>(size_in_bytes: u64) -> u64 {
     if size_in_bytes > N::EXECUTION_STORAGE_PENALTY_THRESHOLD {
@@ -450,6 +493,47 @@ function over_five_thousand:
         test_storage_cost_bounds::<TestnetV0>();
     }
 
+    #[test]
+    fn test_required_fee() {
+        // Construct the process.
+        let program = Program::<MainnetV0>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+        )
+        .unwrap();
+        let process = crate::test_helpers::sample_process(&program);
+
+        // Sample the call metrics.
+        let metrics = [CallMetrics {
+            program_id: *program.id(),
+            function_name: Identifier::from_str("compute").unwrap(),
+            num_instructions: 1,
+            num_request_constraints: 10,
+            num_function_constraints: 20,
+            num_response_constraints: 30,
+        }];
+        let required_fee = |microcredits_per_constraint| {
+            process.required_fee(&metrics, 1_000, FeeRates { microcredits_per_constraint }).unwrap()
+        };
+
+        // Ensure a zero rate only charges for storage.
+        let base_fee = required_fee(0);
+        assert_eq!(base_fee, execution_storage_cost::<MainnetV0>(1_000));
+
+        // Ensure doubling the rate per constraint doubles the constraint portion of the fee.
+        assert_eq!(required_fee(1) - base_fee, 60);
+        assert_eq!(required_fee(2) - base_fee, 120);
+
+        // Ensure empty metrics are rejected.
+        assert!(process.required_fee(&[], 1_000, FeeRates { microcredits_per_constraint: 1 }).is_err());
+    }
+
     #[test]
     fn test_storage_costs_compute_correctly() {
         // Test the storage cost of an execution.
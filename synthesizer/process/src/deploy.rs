@@ -24,6 +24,10 @@ impl<N: Network> Process<N> {
     ) -> Result<Deployment<N>> {
         let timer = timer!("Process::deploy");
 
+        // Ensure the references in the program are defined.
+        self.validate_program(program)?;
+        lap!(timer, "Validate the program");
+
         // Compute the stack.
         let stack = Stack::new(self, program)?;
         lap!(timer, "Compute the stack");
@@ -0,0 +1,167 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The differences between the functions of two versions of a program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProgramDiff<N: Network> {
+    /// The functions that only exist in the new program.
+    added: Vec<Identifier<N>>,
+    /// The functions that only exist in the old program.
+    removed: Vec<Identifier<N>>,
+    /// The functions that exist in both programs, but differ.
+    changed: Vec<Identifier<N>>,
+    /// The changed functions whose input or output types differ.
+    breaking: Vec<Identifier<N>>,
+}
+
+impl<N: Network> Default for ProgramDiff<N> {
+    /// Returns an empty diff.
+    fn default() -> Self {
+        Self { added: Vec::new(), removed: Vec::new(), changed: Vec::new(), breaking: Vec::new() }
+    }
+}
+
+impl<N: Network> ProgramDiff<N> {
+    /// Returns the functions that only exist in the new program.
+    pub fn added(&self) -> &[Identifier<N>] {
+        &self.added
+    }
+
+    /// Returns the functions that only exist in the old program.
+    pub fn removed(&self) -> &[Identifier<N>] {
+        &self.removed
+    }
+
+    /// Returns the functions that exist in both programs, but differ.
+    pub fn changed(&self) -> &[Identifier<N>] {
+        &self.changed
+    }
+
+    /// Returns the changed functions whose input or output types differ.
+    pub fn breaking(&self) -> &[Identifier<N>] {
+        &self.breaking
+    }
+
+    /// Returns `true` if the new program breaks callers of the old program,
+    /// i.e. if a function is removed, or if the input or output types of a function change.
+    pub fn is_breaking(&self) -> bool {
+        !self.removed.is_empty() || !self.breaking.is_empty()
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns the differences between the functions of the old and new program.
+    /// Note: This compares the functions statically, so the programs do not need to be added to the process.
+    pub fn diff_programs(old: &Program<N>, new: &Program<N>) -> ProgramDiff<N> {
+        let mut diff = ProgramDiff::default();
+        for (name, old_function) in old.functions() {
+            match new.functions().get(name) {
+                // If the function is removed, record it.
+                None => diff.removed.push(*name),
+                // If the function differs, record it, and whether its signature differs.
+                Some(new_function) if new_function != old_function => {
+                    diff.changed.push(*name);
+                    if new_function.input_types() != old_function.input_types()
+                        || new_function.output_types() != old_function.output_types()
+                    {
+                        diff.breaking.push(*name);
+                    }
+                }
+                Some(_) => (),
+            }
+        }
+        // Record the functions that are added.
+        diff.added = new.functions().keys().filter(|name| !old.functions().contains_key(*name)).copied().collect();
+        diff
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_diff_programs() {
+        // Initialize the old program.
+        let old = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;
+
+function double:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.private;",
+        )
+        .unwrap();
+        // Initialize the new program, which adds an output to `compute`, and changes the body of `double`.
+        let new = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;
+    output r0 as u32.private;
+
+function double:
+    input r0 as u32.private;
+    mul r0 2u32 into r1;
+    output r1 as u32.private;",
+        )
+        .unwrap();
+
+        // Ensure the added output is flagged as a breaking change.
+        let compute = Identifier::from_str("compute").unwrap();
+        let double = Identifier::from_str("double").unwrap();
+        let diff = Process::diff_programs(&old, &new);
+        assert_eq!(diff.changed(), [compute, double]);
+        assert_eq!(diff.breaking(), [compute]);
+        assert!(diff.added().is_empty() && diff.removed().is_empty());
+        assert!(diff.is_breaking());
+
+        // Ensure identical programs have no differences.
+        assert_eq!(Process::diff_programs(&old, &old), ProgramDiff::default());
+
+        // Ensure an added function is not breaking, and a removed function is breaking.
+        let single = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function double:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.private;",
+        )
+        .unwrap();
+        let diff = Process::diff_programs(&single, &old);
+        assert_eq!(diff.added(), [compute]);
+        assert!(!diff.is_breaking());
+        let diff = Process::diff_programs(&old, &single);
+        assert_eq!(diff.removed(), [compute]);
+        assert!(diff.is_breaking());
+    }
+}
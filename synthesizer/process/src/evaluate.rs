@@ -14,6 +14,41 @@
 
 use super::*;
 
+use console::{program::TransitionLeaf, types::Address};
+
+/// The result of evaluating a program function, which is the response along with the transition root and leaves.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EvaluateResult<N: Network> {
+    /// The response of the function.
+    response: Response<N>,
+    /// The root of the transition tree, over the input and output IDs.
+    trace_root: Field<N>,
+    /// The leaves of the transition tree, which are the input leaves followed by the output leaves.
+    leaves: Vec<TransitionLeaf<N>>,
+}
+
+impl<N: Network> EvaluateResult<N> {
+    /// Returns the response of the function.
+    pub const fn response(&self) -> &Response<N> {
+        &self.response
+    }
+
+    /// Returns the root of the transition tree.
+    pub const fn trace_root(&self) -> &Field<N> {
+        &self.trace_root
+    }
+
+    /// Returns the leaves of the transition tree, in order of their index in the tree.
+    pub fn leaves(&self) -> &[TransitionLeaf<N>] {
+        &self.leaves
+    }
+
+    /// Returns the response of the function.
+    pub fn into_response(self) -> Response<N> {
+        self.response
+    }
+}
+
 impl<N: Network> Process<N> {
     /// Evaluates a program function on the given request.
     #[inline]
@@ -36,4 +71,148 @@ impl<N: Network> Process<N> {
 
         response
     }
+
+    /// Evaluates a program function on the given request, and returns the response
+    /// along with a snapshot of the value assigned to each register of the function.
+    #[inline]
+    pub fn evaluate_traced<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+    ) -> Result<(Response<N>, IndexMap<Register<N>, Value<N>>)> {
+        // Retrieve the top-level request (without popping it).
+        let request = authorization.peek_next()?;
+        // Retrieve the stack.
+        let stack = self.get_stack(request.program_id())?;
+        // Evaluate the function.
+        stack.evaluate_function_traced::<A>(CallStack::evaluate(authorization)?, None)
+    }
+
+    /// Evaluates a program function on the given request, and returns the response along with the events
+    /// emitted by the function (and its calls), which are their public outputs, in the order they were emitted.
+    #[inline]
+    pub fn evaluate_with_events<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+    ) -> Result<(Response<N>, Vec<Event<N>>)> {
+        // Retrieve the top-level request (without popping it).
+        let request = authorization.peek_next()?;
+        // Retrieve the stack.
+        let stack = self.get_stack(request.program_id())?;
+        // Initialize the events.
+        let events = Events::default();
+        // Evaluate the function.
+        let response = stack.evaluate_function::<A>(CallStack::Evaluate(authorization, events.clone()), None)?;
+        // Return the response and the events.
+        let events = events.read().clone();
+        Ok((response, events))
+    }
+
+    /// Evaluates a program function on the given request, if the program satisfies the given policy.
+    #[inline]
+    pub fn evaluate_with_policy<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+        policy: &StackPolicy,
+    ) -> Result<Response<N>> {
+        // Ensure the program satisfies the policy.
+        self.get_stack(authorization.peek_next()?.program_id())?.check_policy(policy)?;
+        // Evaluate the function.
+        self.evaluate::<A>(authorization)
+    }
+
+    /// Evaluates a program function on the given request, and returns the response along with
+    /// the transition root and leaves of the top-level transition, as they are computed in `execute`.
+    #[inline]
+    pub fn evaluate_full<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+    ) -> Result<EvaluateResult<N>> {
+        // Retrieve the top-level request (without popping it).
+        let request = authorization.peek_next()?;
+        // Retrieve the stack.
+        let stack = self.get_stack(request.program_id())?;
+        // Retrieve the function.
+        let function = stack.get_function(request.function_name())?;
+        // Evaluate the function.
+        let response = stack.evaluate_function::<A>(CallStack::evaluate(authorization)?, None)?;
+
+        // Map the output operands into registers.
+        let output_registers = function
+            .outputs()
+            .iter()
+            .map(|output| match output.operand() {
+                Operand::Register(register) => Some(register.clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        // Construct the transition.
+        let transition = Transition::from(&request, &response, &function.output_types(), &output_registers)?;
+
+        // Compute the transition root.
+        let trace_root = transition.to_root()?;
+        // Collect the transition leaves, as they are computed in the transition tree.
+        let num_inputs = transition.inputs().len();
+        let input_leaves = transition
+            .inputs()
+            .iter()
+            .enumerate()
+            .map(|(index, input)| Ok::<_, Error>(input.to_transition_leaf(u8::try_from(index)?)));
+        let output_leaves = transition
+            .outputs()
+            .iter()
+            .enumerate()
+            .map(|(index, output)| Ok(output.to_transition_leaf(u8::try_from(num_inputs + index)?)));
+        let leaves = input_leaves.chain(output_leaves).collect::<Result<Vec<_>>>()?;
+        Ok(EvaluateResult { response, trace_root, leaves })
+    }
+
+    /// Evaluates a program closure on the given inputs, without a request.
+    /// Note: As there is no request, the signer and caller are the zero address, and the transition view key is zero.
+    #[inline]
+    pub fn evaluate_closure<A: circuit::Aleo<Network = N>>(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        closure_name: impl TryInto<Identifier<N>>,
+        inputs: &[Value<N>],
+    ) -> Result<Vec<Value<N>>> {
+        // Prepare the closure name.
+        let closure_name = closure_name.try_into().map_err(|_| anyhow!("Invalid closure name"))?;
+        // Retrieve the stack.
+        let stack = self.get_stack(program_id)?;
+        // Retrieve the closure.
+        let closure = stack.program().get_closure(&closure_name)?;
+        // Initialize an empty authorization, as the closure does not consume a request.
+        let authorization = Authorization::try_from((vec![], vec![]))?;
+        // Evaluate the closure.
+        stack.evaluate_closure::<A>(
+            &closure,
+            inputs,
+            CallStack::evaluate(authorization)?,
+            Address::zero(),
+            Address::zero(),
+            Field::zero(),
+        )
+    }
+
+    /// Returns the output of the given response for the function, which is declared as `output {register} as ...`.
+    /// Note: If several outputs of the function declare the register, the first such output is returned.
+    pub fn output_by_register<'a>(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        response: &'a Response<N>,
+        register: &Register<N>,
+    ) -> Result<&'a Value<N>> {
+        // Prepare the function name.
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+        // Retrieve the function.
+        let function = self.get_stack(program_id)?.get_function(&function_name)?;
+        // Find the index of the output that declares the register.
+        let operand = Operand::Register(register.clone());
+        let Some(index) = function.outputs().iter().position(|output| output.operand() == &operand) else {
+            bail!("Function '{function_name}' does not output register '{register}'")
+        };
+        // Retrieve the output.
+        response.output(index).ok_or_else(|| anyhow!("The response is missing output {index} of '{function_name}'"))
+    }
 }
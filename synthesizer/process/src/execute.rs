@@ -14,13 +14,170 @@
 
 use super::*;
 
+use ledger_block::Output;
+use ledger_query::QueryTrait;
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+/// Whether the circuit logs (printed in debug builds) are colored.
+/// Note: This is a global setting, rather than a field of `Process` or `Stack`, as the logs are printed by
+/// `Stack::log_circuit` from the circuit environment (which is itself global), and are written to the shared stdout.
+pub(crate) static LOG_COLOR: AtomicBool = AtomicBool::new(true);
+
 impl<N: Network> Process<N> {
+    /// Enables or disables the color of the circuit logs, which are printed in debug builds.
+    /// Note: This setting applies to every process, and color is also disabled by `colored`
+    /// if `NO_COLOR` is set or if stdout is not a terminal.
+    pub fn set_log_color(enabled: bool) {
+        LOG_COLOR.store(enabled, Ordering::Relaxed);
+    }
+
     /// Executes the given authorization.
     #[inline]
     pub fn execute<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
         &self,
         authorization: Authorization<N>,
         rng: &mut R,
+    ) -> Result<(Response<N>, Trace<N>)> {
+        // Retrieve the stack.
+        let stack = self.get_stack(authorization.peek_next()?.program_id())?;
+        // Execute the authorization.
+        Self::execute_with_trace::<A, R>(stack, authorization, Trace::new(), rng)
+    }
+
+    /// Executes the given authorization against the given snapshot of the program state.
+    /// Note: The programs in the snapshot are used (rather than the programs in the process),
+    /// so the execution is reproducible even if newer programs are later added to the process.
+    #[inline]
+    pub fn execute_at<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        &self,
+        authorization: Authorization<N>,
+        snapshot: &ProcessSnapshot<N>,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Trace<N>)> {
+        // Retrieve the stack from the snapshot.
+        let stack = snapshot.get_stack(authorization.peek_next()?.program_id())?;
+        // Execute the authorization.
+        Self::execute_with_trace::<A, R>(stack, authorization, Trace::new(), rng)
+    }
+
+    /// Executes the given authorization, and aborts the execution if it exceeds the given timeout.
+    /// Note: The timeout is checked before each instruction, so a single instruction is never interrupted.
+    /// If the deadline overflows `Instant` (e.g. for `Duration::MAX`), the execution has no deadline.
+    #[inline]
+    pub fn execute_with_timeout<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        &self,
+        authorization: Authorization<N>,
+        timeout: Duration,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Trace<N>)> {
+        // Initialize the trace, with the deadline of the execution (if it is representable).
+        let mut trace = Trace::new();
+        if let Some(deadline) = Instant::now().checked_add(timeout) {
+            trace.set_deadline(deadline);
+        }
+        // Retrieve the stack.
+        let stack = self.get_stack(authorization.peek_next()?.program_id())?;
+        // Execute the authorization.
+        Self::execute_with_trace::<A, R>(stack, authorization, trace, rng)
+    }
+
+    /// Executes the given authorization, and returns the values of the public variables in the circuit assignment
+    /// of the top-level function, which are the public inputs to verify its proof (i.e. `tpk`, `tcm`, `scm`,
+    /// the input IDs, `is_root`, `parent`, the inputs and outputs of external calls, and the output IDs).
+    /// Note: This synthesizes the circuits, but does not compute the proofs.
+    #[inline]
+    pub fn public_witness<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        &self,
+        authorization: Authorization<N>,
+        rng: &mut R,
+    ) -> Result<Vec<Field<N>>> {
+        // Construct the locator of the top-level function.
+        let request = authorization.peek_next()?;
+        let locator = Locator::new(*request.program_id(), *request.function_name());
+        // Execute the authorization.
+        let (_, trace) = self.execute::<A, R>(authorization, rng)?;
+        // Retrieve the assignment of the top-level function, which is inserted last.
+        let Some(assignment) = trace.assignments(&locator).and_then(|assignments| assignments.last()) else {
+            bail!("Missing the circuit assignment for '{locator}'")
+        };
+        // Return the values of the public variables.
+        Ok(assignment.public_inputs().iter().map(|(_, value)| Field::new(*value)).collect())
+    }
+
+    /// Executes the given authorization, and proves the execution against the global state root of the given query.
+    /// Returns the response, and the execution with its proof.
+    /// Note: The proving keys are retrieved from the same cache as `prover_key`, and synthesized if they do not exist.
+    #[inline]
+    pub fn prove<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        &self,
+        authorization: Authorization<N>,
+        query: impl QueryTrait<N>,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Execution<N>)> {
+        // Construct the locator of the main function.
+        let locator = {
+            let request = authorization.peek_next()?;
+            Locator::new(*request.program_id(), *request.function_name()).to_string()
+        };
+        // Execute the authorization.
+        let (response, mut trace) = self.execute::<A, R>(authorization, rng)?;
+        // Prepare the inclusion assignments.
+        trace.prepare(query)?;
+        // Compute the proof and construct the execution.
+        let execution = trace.prove_execution::<A, R>(&locator, rng)?;
+        Ok((response, execution))
+    }
+
+    /// Evaluates and executes the given authorization, and ensures the outputs of the native evaluation
+    /// match the (ejected) outputs of the circuit execution, element-wise.
+    /// Note: This is a diagnostic for program authors, and is not used by consensus.
+    #[inline]
+    pub fn differential_check<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        &self,
+        authorization: Authorization<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        // Evaluate the authorization natively.
+        let native = self.evaluate::<A>(authorization.replicate())?;
+        // Execute the authorization in the circuit.
+        let (circuit, _) = self.execute::<A, R>(authorization, rng)?;
+        // Ensure the outputs match.
+        Self::ensure_outputs_match(native.outputs(), circuit.outputs())
+    }
+
+    /// Ensures the given native outputs match the given circuit outputs, element-wise.
+    /// On mismatch, the error lists every output that differs.
+    pub(crate) fn ensure_outputs_match(native: &[Value<N>], circuit: &[Value<N>]) -> Result<()> {
+        // Ensure the number of outputs match.
+        ensure!(
+            native.len() == circuit.len(),
+            "Differential check failed: evaluate returned {} outputs, execute returned {} outputs",
+            native.len(),
+            circuit.len()
+        );
+        // Collect the outputs that differ.
+        let diff = native
+            .iter()
+            .zip_eq(circuit)
+            .enumerate()
+            .filter(|(_, (native, circuit))| native != circuit)
+            .map(|(index, (native, circuit))| format!("  output {index}: evaluate '{native}' != execute '{circuit}'"))
+            .collect::<Vec<_>>();
+        // Ensure no outputs differ.
+        ensure!(diff.is_empty(), "Differential check failed:\n{}", diff.join("\n"));
+        Ok(())
+    }
+
+    /// Executes the given authorization on the given stack, starting from the given trace.
+    #[inline]
+    fn execute_with_trace<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        stack: &Arc<Stack<N>>,
+        authorization: Authorization<N>,
+        trace: Trace<N>,
+        rng: &mut R,
     ) -> Result<(Response<N>, Trace<N>)> {
         let timer = timer!("Process::execute");
 
@@ -37,13 +194,11 @@ impl<N: Network> Process<N> {
         // This is the root request and we do not have a root_tvk to pass on.
         let root_tvk = None;
         // Initialize the trace.
-        let trace = Arc::new(RwLock::new(Trace::new()));
+        let trace = Arc::new(RwLock::new(trace));
         // Initialize the call stack.
         let call_stack = CallStack::execute(authorization, trace.clone())?;
         lap!(timer, "Initialize call stack");
 
-        // Retrieve the stack.
-        let stack = self.get_stack(request.program_id())?;
         // Execute the circuit.
         let response = stack.execute_function::<A, R>(call_stack, caller, root_tvk, rng)?;
         lap!(timer, "Execute the function");
@@ -56,6 +211,166 @@ impl<N: Network> Process<N> {
         finish!(timer);
         Ok((response, trace))
     }
+
+    /// Executes the given authorization, if the program satisfies the given policy.
+    #[inline]
+    pub fn execute_with_policy<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        &self,
+        authorization: Authorization<N>,
+        policy: &StackPolicy,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Trace<N>)> {
+        // Ensure the program satisfies the policy.
+        self.get_stack(authorization.peek_next()?.program_id())?.check_policy(policy)?;
+        // Execute the authorization.
+        self.execute::<A, R>(authorization, rng)
+    }
+
+    /// Executes the given authorization, and returns the response and the circuit assignment of the root transition.
+    /// Note: The assignment contains every variable and constraint of the circuit, and may be large.
+    #[cfg(feature = "assignment")]
+    #[inline]
+    pub fn execute_with_assignment<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        &self,
+        authorization: Authorization<N>,
+        rng: &mut R,
+    ) -> Result<(Response<N>, circuit::Assignment<N::Field>)> {
+        // Retrieve the main request (without popping it).
+        let request = authorization.peek_next()?;
+        // Construct the locator.
+        let locator = Locator::new(*request.program_id(), *request.function_name());
+
+        // Execute the authorization.
+        let (response, trace) = self.execute::<A, R>(authorization, rng)?;
+        // Retrieve the assignment of the root transition.
+        // Note: The root transition is inserted last, as it completes after the transitions it calls.
+        match trace.assignments(&locator).and_then(|assignments| assignments.last()) {
+            Some(assignment) => Ok((response, assignment.clone())),
+            None => bail!("Missing the assignment for '{locator}'"),
+        }
+    }
+
+    /// Executes the given authorization on a dedicated thread, and returns the response and trace.
+    /// As the circuit environment is thread-local, the execution does not reset or otherwise mutate
+    /// the circuit environment of the calling thread.
+    #[inline]
+    pub fn execute_isolated<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng + Send>(
+        &self,
+        authorization: Authorization<N>,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Trace<N>)> {
+        std::thread::scope(|scope| {
+            // Execute the authorization on a dedicated thread.
+            let handle = scope.spawn(|| self.execute::<A, R>(authorization, rng));
+            // Return the result of the execution.
+            handle.join().map_err(|_| anyhow!("The isolated execution thread panicked"))?
+        })
+    }
+
+    /// Authorizes and executes the given function, where each input is given as a list of field elements.
+    /// The inputs are reconstructed from their field elements, using the declared input types of the function.
+    /// Note: Record inputs can not be reconstructed from field elements, and must be passed to `authorize` instead.
+    #[inline]
+    pub fn execute_from_fields<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        input_fields: Vec<Vec<Field<N>>>,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Trace<N>)> {
+        // Prepare the program ID.
+        let program_id = program_id.try_into().map_err(|_| anyhow!("Invalid program ID"))?;
+        // Prepare the function name.
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+        // Retrieve the function.
+        let function = self.get_stack(program_id)?.get_function(&function_name)?;
+
+        // Ensure the number of inputs matches the number of input statements.
+        if function.inputs().len() != input_fields.len() {
+            bail!(
+                "Function '{function_name}' expects {} inputs, but {} were given.",
+                function.inputs().len(),
+                input_fields.len()
+            )
+        }
+
+        // Reconstruct the inputs from their field elements.
+        let inputs = function
+            .inputs()
+            .iter()
+            .zip_eq(input_fields)
+            .map(|(input, fields)| match input.value_type() {
+                ValueType::Constant(..) | ValueType::Public(..) | ValueType::Private(..) => {
+                    Ok(Value::Plaintext(Plaintext::from_fields(&fields)?))
+                }
+                value_type => bail!("Cannot reconstruct a '{value_type}' input from field elements"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Authorize the function call.
+        let authorization = self.authorize::<A, R>(private_key, program_id, function_name, inputs.iter(), rng)?;
+        // Execute the authorization.
+        self.execute::<A, R>(authorization, rng)
+    }
+
+    /// Returns the number of constraints in the circuit of the given (root) request, without proving.
+    /// Note: The circuit is synthesized as in a deployment check, so the calls to external functions are
+    /// not synthesized, and the count matches the circuit that is proven for the request.
+    #[inline]
+    pub fn constraint_count<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        &self,
+        request: &Request<N>,
+        rng: &mut R,
+    ) -> Result<u64> {
+        // Retrieve the stack.
+        let stack = self.get_stack(request.program_id())?;
+        // Initialize a burner private key, to sign the requests of the external calls.
+        let burner_private_key = PrivateKey::new(rng)?;
+        // Initialize the assignments.
+        let assignments = Assignments::<N>::default();
+        // Initialize the call stack, without a constraint limit or variable limit.
+        let call_stack =
+            CallStack::CheckDeployment(vec![request.clone()], burner_private_key, assignments.clone(), None, None);
+        // Synthesize the circuit.
+        stack.execute_function::<A, R>(call_stack, None, None, rng)?;
+
+        // Return the number of constraints of the assignment.
+        match assignments.read().last() {
+            Some((assignment, _metrics)) => Ok(assignment.num_constraints()),
+            None => bail!("The assignment for '{}/{}' is missing", request.program_id(), request.function_name()),
+        }
+    }
+
+    /// Returns the value of the given output as a circuit value in the given mode, to use as an input to a circuit.
+    /// As a record input is injected as its plaintext, a record output is decrypted with the given view key.
+    /// Note: A private output is encrypted under its transition, and an external record has no value,
+    /// so both are rejected.
+    pub fn output_to_circuit_input<A: circuit::Aleo<Network = N>>(
+        output: &Output<N>,
+        view_key: Option<&ViewKey<N>>,
+        mode: circuit::Mode,
+    ) -> Result<circuit::Value<A>> {
+        use circuit::Inject;
+
+        // Retrieve the console value of the output.
+        let value = match output {
+            Output::Constant(_, Some(plaintext)) | Output::Public(_, Some(plaintext)) => {
+                Value::Plaintext(plaintext.clone())
+            }
+            Output::Record(_, _, Some(record)) => {
+                let Some(view_key) = view_key else { bail!("A view key is required to inject a record output") };
+                ensure!(record.is_owner(view_key), "The view key does not own the record output");
+                Value::Record(record.decrypt(view_key)?)
+            }
+            Output::Future(_, Some(future)) => Value::Future(future.clone()),
+            Output::Private(..) => bail!("Cannot inject a private output, as it is encrypted under its transition"),
+            Output::ExternalRecord(..) => bail!("Cannot inject an external record output, as it has no value"),
+            _ => bail!("Cannot inject an output with a missing value"),
+        };
+        // Inject the value.
+        Ok(circuit::Value::new(mode, value))
+    }
 }
 
 #[cfg(test)]
@@ -118,6 +433,30 @@ mod tests {
         assert!(transition.is_fee_private(), "Transition must be for 'credits.aleo/fee_private'");
     }
 
+    #[test]
+    fn test_authorize_fee_private_rejects_zero_balance() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+
+        // Sample a private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let owner = Address::try_from(private_key).unwrap();
+
+        // Sample a credits record with a zero balance.
+        let credits = Record::<CurrentNetwork, Plaintext<_>>::from_str(&format!(
+            "{{ owner: {owner}.private, microcredits: 0u64.private, _nonce: 0group.public }}"
+        ))
+        .unwrap();
+
+        // Ensure the record is rejected, even for a zero fee.
+        let error = process
+            .authorize_fee_private::<CurrentAleo, _>(&private_key, credits, 0, 0, Field::rand(rng), rng)
+            .unwrap_err();
+        assert_eq!(error.to_string(), "Fee record has zero balance");
+    }
+
     #[test]
     fn test_execute_fee_public() {
         let rng = &mut TestRng::default();
@@ -159,4 +498,192 @@ mod tests {
         let transition = trace.transitions()[0].clone();
         assert!(transition.is_fee_public(), "Transition must be for 'credits.aleo/fee_public'");
     }
+
+    #[cfg(feature = "assignment")]
+    #[test]
+    fn test_execute_with_assignment() {
+        use console::program::OutputID;
+
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+
+        // Sample a private key.
+        let private_key = PrivateKey::new(rng).unwrap();
+
+        // Compute the authorization.
+        let authorization = process
+            .authorize_fee_public::<CurrentAleo, _>(
+                &private_key,
+                rng.gen_range(1_000_000..u64::MAX / 2),
+                rng.gen_range(0..u64::MAX / 2),
+                Field::rand(rng),
+                rng,
+            )
+            .unwrap();
+
+        // Execute the authorization, and retrieve the assignment.
+        let (response, assignment) = process.execute_with_assignment::<CurrentAleo, _>(authorization, rng).unwrap();
+        // Ensure the assignment is non-trivial.
+        assert!(assignment.num_private() > 0);
+        assert!(assignment.num_constraints() > 0);
+
+        // Ensure the public inputs of the assignment end with the output IDs (and checksums).
+        let output_ids = response
+            .output_ids()
+            .iter()
+            .flat_map(|output_id| match output_id {
+                OutputID::Record(commitment, checksum) => vec![*commitment, *checksum],
+                OutputID::Constant(id)
+                | OutputID::Public(id)
+                | OutputID::Private(id)
+                | OutputID::ExternalRecord(id)
+                | OutputID::Future(id) => vec![*id],
+            })
+            .collect::<Vec<_>>();
+        let public_inputs = assignment.public_inputs();
+        let num_public_inputs = public_inputs.len();
+        assert!(num_public_inputs > output_ids.len());
+        let public_outputs = public_inputs[num_public_inputs - output_ids.len()..]
+            .iter()
+            .map(|(_, value)| Field::<CurrentNetwork>::new(*value))
+            .collect::<Vec<_>>();
+        assert_eq!(public_outputs, output_ids);
+    }
+
+    #[test]
+    fn test_constraint_count() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+
+        // Sample a private key.
+        let private_key = PrivateKey::new(rng).unwrap();
+
+        // Compute two public fee authorizations.
+        let authorizations = (0..2)
+            .map(|_| {
+                process
+                    .authorize_fee_public::<CurrentAleo, _>(
+                        &private_key,
+                        rng.gen_range(1_000_000..u64::MAX / 2),
+                        rng.gen_range(0..u64::MAX / 2),
+                        Field::rand(rng),
+                        rng,
+                    )
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        // Count the constraints of each request.
+        let counts = authorizations
+            .iter()
+            .map(|authorization| {
+                let request = authorization.peek_next().unwrap();
+                process.constraint_count::<CurrentAleo, _>(&request, rng).unwrap()
+            })
+            .collect::<Vec<_>>();
+        // Ensure the constraint count is stable across requests.
+        assert!(counts[0] > 0);
+        assert_eq!(counts[0], counts[1]);
+
+        // Ensure the constraint count matches the constraints of the execution.
+        let (_, trace) = process.execute::<CurrentAleo, _>(authorizations[0].replicate(), rng).unwrap();
+        let metrics = &trace.call_metrics()[0];
+        assert_eq!(
+            counts[0],
+            metrics.num_request_constraints + metrics.num_function_constraints + metrics.num_response_constraints
+        );
+    }
+
+    #[test]
+    fn test_execute_isolated() {
+        use circuit::{Environment, Inject};
+
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+
+        // Sample a private key.
+        let private_key = PrivateKey::new(rng).unwrap();
+
+        // Compute two public fee authorizations.
+        let authorizations = (0..2)
+            .map(|_| {
+                process
+                    .authorize_fee_public::<CurrentAleo, _>(
+                        &private_key,
+                        rng.gen_range(1_000_000..u64::MAX / 2),
+                        rng.gen_range(0..u64::MAX / 2),
+                        Field::rand(rng),
+                        rng,
+                    )
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        // Inject a variable into the circuit environment of this thread.
+        let _variable = circuit::Field::<CurrentAleo>::new(circuit::Mode::Private, Field::rand(rng));
+        let num_private = CurrentAleo::num_private();
+
+        // Execute each authorization on a dedicated thread.
+        let mut call_metrics = Vec::new();
+        for authorization in authorizations {
+            let (_, trace) = process.execute_isolated::<CurrentAleo, _>(authorization, rng).unwrap();
+            call_metrics.extend_from_slice(trace.call_metrics());
+        }
+
+        // Ensure each execution counted its constraints in its own circuit environment.
+        assert_eq!(call_metrics.len(), 2);
+        assert_eq!(call_metrics[0].num_request_constraints, call_metrics[1].num_request_constraints);
+        assert_eq!(call_metrics[0].num_function_constraints, call_metrics[1].num_function_constraints);
+        assert_eq!(call_metrics[0].num_response_constraints, call_metrics[1].num_response_constraints);
+        // Ensure the circuit environment of this thread is unchanged.
+        assert_eq!(CurrentAleo::num_private(), num_private);
+    }
+
+    #[test]
+    fn test_output_to_circuit_input() {
+        use circuit::Eject;
+
+        let rng = &mut TestRng::default();
+
+        // Sample an account.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let owner = Address::try_from(&private_key).unwrap();
+
+        // Sample a record output.
+        let randomizer = Uniform::rand(rng);
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {owner}.private, microcredits: 100u64.private, _nonce: {nonce}.public }}"
+        ))
+        .unwrap();
+        let output = Output::Record(Field::rand(rng), Field::rand(rng), Some(record.encrypt(randomizer).unwrap()));
+
+        // Ensure the record output is injected, and ejects back to the same record.
+        let mode = circuit::Mode::Private;
+        let value =
+            Process::<CurrentNetwork>::output_to_circuit_input::<CurrentAleo>(&output, Some(&view_key), mode).unwrap();
+        assert_eq!(value.eject_value(), Value::Record(record));
+
+        // Ensure the record output requires the view key of its owner.
+        let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        assert!(Process::output_to_circuit_input::<CurrentAleo>(&output, Some(&other_view_key), mode).is_err());
+        assert!(Process::output_to_circuit_input::<CurrentAleo>(&output, None, mode).is_err());
+
+        // Ensure a public output is injected as its plaintext.
+        let plaintext = Plaintext::from_str("5u64").unwrap();
+        let output = Output::<CurrentNetwork>::Public(Field::rand(rng), Some(plaintext.clone()));
+        let value = Process::output_to_circuit_input::<CurrentAleo>(&output, None, circuit::Mode::Public).unwrap();
+        assert_eq!(value.eject_value(), Value::Plaintext(plaintext));
+
+        // Ensure an external record output is rejected.
+        let output = Output::<CurrentNetwork>::ExternalRecord(Field::rand(rng));
+        assert!(Process::output_to_circuit_input::<CurrentAleo>(&output, None, mode).is_err());
+    }
 }
@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use rand::SeedableRng;
+use rand_chacha::ChaChaRng;
+use utilities::try_vm_runtime;
+
+impl<N: Network> Process<N> {
+    /// Authorizes the function on the given inputs, then evaluates and executes the authorization,
+    /// and asserts the outputs of `evaluate` and `execute` agree. This is the entry point for differential fuzzing.
+    ///
+    /// The private key and the RNG are seeded deterministically, so a failing input can be replayed.
+    /// Malformed or ill-typed inputs return an error, as do inputs that fail or halt in both `evaluate` and `execute`.
+    ///
+    /// # Panics
+    /// This method panics if `evaluate` and `execute` disagree, which is the divergence for a fuzzer to report.
+    pub fn fuzz_execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: &[&str],
+    ) -> Result<()> {
+        // Initialize the RNG and the private key.
+        let rng = &mut ChaChaRng::seed_from_u64(0);
+        let private_key = PrivateKey::new(rng)?;
+
+        // Authorize the function call, which ensures the inputs are well-formed and match the input types.
+        let authorization = match try_vm_runtime!(|| {
+            self.authorize::<A, _>(&private_key, program_id, function_name, inputs.iter().copied(), rng)
+        }) {
+            Ok(result) => result?,
+            Err(_) => bail!("Failed to authorize the fuzzed inputs"),
+        };
+
+        // Evaluate and execute the authorization, treating a halt as an error.
+        let native = try_vm_runtime!(|| self.evaluate::<A>(authorization.replicate()))
+            .unwrap_or_else(|_| Err(anyhow!("'evaluate' halted")));
+        let circuit = try_vm_runtime!(|| self.execute::<A, _>(authorization, rng))
+            .unwrap_or_else(|_| Err(anyhow!("'execute' halted")));
+
+        // Ensure the results agree.
+        match (native, circuit) {
+            (Ok(native), Ok((circuit, _))) => {
+                if let Err(error) = Self::ensure_outputs_match(native.outputs(), circuit.outputs()) {
+                    panic!("{error}")
+                }
+                Ok(())
+            }
+            (Err(native), Err(circuit)) => {
+                bail!("The fuzzed inputs were rejected by 'evaluate' ({native}) and by 'execute' ({circuit})")
+            }
+            (Ok(_), Err(error)) => panic!("Differential check failed: only 'execute' failed: {error}"),
+            (Err(error), Ok(_)) => panic!("Differential check failed: only 'evaluate' failed: {error}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_fuzz_execute() {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+        )
+        .unwrap();
+
+        // Construct the process.
+        let process = crate::test_helpers::sample_process(&program);
+        let fuzz = |inputs: &[&str]| process.fuzz_execute::<CurrentAleo>(program.id(), "compute", inputs);
+
+        // Ensure the seed corpus of well-formed inputs is accepted.
+        for inputs in [["5u32", "10u32"], ["0u32", "0u32"], ["4294967295u32", "0u32"]] {
+            fuzz(&inputs).unwrap();
+        }
+
+        // Ensure malformed inputs, and inputs that halt, return an error.
+        assert!(fuzz(&["5u32"]).is_err());
+        assert!(fuzz(&["5u64", "10u32"]).is_err());
+        assert!(fuzz(&["garbage", "10u32"]).is_err());
+        assert!(fuzz(&["4294967295u32", "1u32"]).is_err());
+    }
+}
@@ -18,9 +18,27 @@
 // TODO (howardwu): Update the return type on `execute` after stabilizing the interface.
 #![allow(clippy::type_complexity)]
 
+mod abi;
+pub use abi::*;
+
+mod benchmark;
+pub use benchmark::*;
+
 mod cost;
 pub use cost::*;
 
+mod diff;
+pub use diff::*;
+
+#[cfg(any(test, feature = "fuzzing"))]
+mod fuzz;
+
+mod profile;
+pub use profile::*;
+
+mod snapshot;
+pub use snapshot::*;
+
 mod stack;
 pub use stack::*;
 
@@ -30,6 +48,9 @@ pub use trace::*;
 mod traits;
 pub use traits::*;
 
+mod validate;
+pub use validate::*;
+
 mod authorize;
 mod deploy;
 mod evaluate;
@@ -43,21 +64,37 @@ mod verify_fee;
 mod tests;
 
 use console::{
-    account::PrivateKey,
+    account::{PrivateKey, ViewKey},
     network::prelude::*,
-    program::{compute_function_id, Identifier, Literal, Locator, Plaintext, ProgramID, Record, Response, Value},
-    types::{Field, U16, U64},
+    program::{
+        compute_function_id,
+        Identifier,
+        Literal,
+        Locator,
+        Plaintext,
+        ProgramID,
+        Record,
+        RecordType,
+        Register,
+        Request,
+        Response,
+        Value,
+        ValueType,
+    },
+    types::{Field, Scalar, U16, U64},
 };
-use ledger_block::{Deployment, Execution, Fee, Input, Transition};
+use ledger_block::{Deployment, Execution, Fee, Input, PublicInputs, Transition};
 use ledger_store::{atomic_batch_scope, FinalizeStorage, FinalizeStore};
 use synthesizer_program::{
     Branch,
+    CallOperator,
     Closure,
     Command,
     Finalize,
     FinalizeGlobalState,
     FinalizeOperation,
     Instruction,
+    Operand,
     Program,
     RegistersLoad,
     RegistersStore,
@@ -122,6 +159,8 @@ impl<N: Network> Process<N> {
         let credits_program_id = ProgramID::<N>::from_str("credits.aleo")?;
         // If the program is not 'credits.aleo', compute the program stack, and add it to the process.
         if program.id() != &credits_program_id {
+            // Ensure the references in the program are defined.
+            self.validate_program(program)?;
             self.add_stack(Stack::new(self, program)?);
         }
         Ok(())
@@ -227,6 +266,119 @@ impl<N: Network> Process<N> {
         Ok(self.get_stack(program_id)?.program())
     }
 
+    /// Returns the record types defined in the program with the given program ID.
+    #[inline]
+    pub fn record_types(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+    ) -> Result<&IndexMap<Identifier<N>, RecordType<N>>> {
+        Ok(self.get_program(program_id)?.records())
+    }
+
+    /// Returns the number of public inputs in the circuit for the given program ID and function name.
+    /// This count is derived from the function signature, and includes the constant `1` of the circuit.
+    #[inline]
+    pub fn num_public_inputs(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+    ) -> Result<usize> {
+        // Prepare the program ID.
+        let program_id = program_id.try_into().map_err(|_| anyhow!("Invalid program ID"))?;
+        // Prepare the function name.
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+        // Retrieve the function.
+        let function = self.get_stack(program_id)?.get_function(&function_name)?;
+
+        // A record produces two public inputs (an ID and a serial number tag or checksum), all others produce one.
+        let num_inputs_for = |value_type: &ValueType<N>| match value_type {
+            ValueType::Record(..) => 2,
+            _ => 1,
+        };
+
+        // Count the constant `1`, the `tpk` coordinates, `tcm`, and `scm`.
+        let mut num_public_inputs = 5;
+        // Count the input IDs.
+        num_public_inputs += function.inputs().iter().map(|input| num_inputs_for(input.value_type())).sum::<usize>();
+        // Count `is_root` and the `parent` coordinates.
+        num_public_inputs += 3;
+        // Count the `tcm`, input IDs, and output IDs of each external function call.
+        for instruction in function.instructions() {
+            if let Instruction::Call(call) = instruction {
+                // Note: Only external functions may be called from a function.
+                if let CallOperator::Locator(locator) = call.operator() {
+                    // Skip the call, if it is a call to a closure.
+                    let Ok(callee) = self.get_stack(locator.program_id())?.get_function(locator.resource()) else {
+                        continue;
+                    };
+                    // Count the transition commitment of the external call.
+                    num_public_inputs += 1;
+                    // Count the input IDs of the external call.
+                    num_public_inputs +=
+                        callee.inputs().iter().map(|input| num_inputs_for(input.value_type())).sum::<usize>();
+                    // Count the output IDs of the external call.
+                    num_public_inputs += callee.outputs().len();
+                }
+            }
+        }
+        // Count the output IDs.
+        num_public_inputs += function.outputs().iter().map(|output| num_inputs_for(output.value_type())).sum::<usize>();
+
+        Ok(num_public_inputs)
+    }
+
+    /// Returns the encryption randomizer of each output of the given function, for the given transition view key.
+    /// The randomizer of a record output is `HashToScalar(tvk || index)`, where `index` is the locator of the
+    /// output register. As only record outputs are encrypted with a randomizer, all other outputs are `None`.
+    #[inline]
+    pub fn output_randomizers(
+        &self,
+        tvk: &Field<N>,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+    ) -> Result<Vec<Option<Scalar<N>>>> {
+        // Prepare the program ID.
+        let program_id = program_id.try_into().map_err(|_| anyhow!("Invalid program ID"))?;
+        // Prepare the function name.
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+        // Retrieve the function.
+        let function = self.get_stack(program_id)?.get_function(&function_name)?;
+
+        // Compute the randomizer of each record output.
+        function
+            .outputs()
+            .iter()
+            .map(|output| match (output.value_type(), output.operand()) {
+                (ValueType::Record(..), Operand::Register(register)) => {
+                    // Construct the (console) output index as a field element.
+                    let index = Field::from_u64(register.locator());
+                    // Compute the encryption randomizer as `HashToScalar(tvk || index)`.
+                    Ok(Some(N::hash_to_scalar_psd2(&[*tvk, index])?))
+                }
+                (ValueType::Record(..), operand) => {
+                    bail!("Expected a register for the record output, found '{operand}'")
+                }
+                _ => Ok(None),
+            })
+            .collect()
+    }
+
+    /// Returns the transition view key of the given transition, recovered from the view key of its signer.
+    /// The transition view key is computed as `(tpk * view_key).x`, which equals `(r * signer).x` from `Request::sign`.
+    /// This ensures the view key belongs to the signer, by checking the transition commitment `tcm == Hash(tvk)`.
+    #[inline]
+    pub fn recover_tvk(&self, view_key: &ViewKey<N>, transition: &Transition<N>) -> Result<Field<N>> {
+        // Compute the transition view key as `tpk * view_key`.
+        let tvk = (*transition.tpk() * **view_key).to_x_coordinate();
+        // Ensure the transition commitment matches.
+        ensure!(
+            N::hash_psd2(&[tvk])? == *transition.tcm(),
+            "The view key is not the signer of transition '{}'",
+            transition.id()
+        );
+        Ok(tvk)
+    }
+
     /// Returns the proving key for the given program ID and function name.
     #[inline]
     pub fn get_proving_key(
@@ -286,6 +438,24 @@ impl<N: Network> Process<N> {
         // Synthesize the proving and verifying key.
         self.get_stack(program_id)?.synthesize_key::<A, R>(function_name, rng)
     }
+
+    /// Returns the proving and verifying key for the given program ID and function name,
+    /// synthesizing and caching them in the stack if they do not exist yet.
+    /// Note: `execute` retrieves the proving key from the same cache, so a key is synthesized once per function.
+    #[inline]
+    pub fn prover_key<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<(ProvingKey<N>, VerifyingKey<N>)> {
+        // Retrieve the stack.
+        let stack = self.get_stack(program_id)?;
+        // Synthesize the proving and verifying key, if they do not exist.
+        stack.synthesize_key::<A, R>(function_name, rng)?;
+        // Return the cached proving and verifying key.
+        Ok((stack.get_proving_key(function_name)?, stack.get_verifying_key(function_name)?))
+    }
 }
 
 #[cfg(any(test, feature = "test"))]
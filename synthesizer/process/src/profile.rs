@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use synthesizer_program::Opcode;
+
+/// The number of instructions of each opcode in a function (or closure), in order of first appearance.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OpcodeHistogram {
+    /// The mapping of opcodes to the number of instructions.
+    counts: IndexMap<Opcode, usize>,
+}
+
+impl OpcodeHistogram {
+    /// Returns the number of instructions with the given opcode, i.e. `add` or `hash.bhp256`.
+    pub fn count(&self, opcode: &str) -> usize {
+        self.counts.iter().filter(|(candidate, _)| **candidate == opcode).map(|(_, count)| count).sum()
+    }
+
+    /// Returns the total number of instructions.
+    pub fn num_instructions(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Returns an iterator over the opcodes and their number of instructions.
+    pub fn iter(&self) -> impl '_ + ExactSizeIterator<Item = (&Opcode, &usize)> {
+        self.counts.iter()
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns the opcode histogram of the given function (or closure), without executing it.
+    /// Note: The instructions of called closures and functions, and of the finalize block, are not included.
+    pub fn function_profile(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+    ) -> Result<OpcodeHistogram> {
+        // Retrieve the program.
+        let program = self.get_program(program_id)?;
+        // Prepare the function name.
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+
+        // Retrieve the instructions of the function or closure.
+        let instructions = match program.contains_function(&function_name) {
+            true => program.get_function_ref(&function_name)?.instructions().to_vec(),
+            false => program.get_closure(&function_name)?.instructions().to_vec(),
+        };
+
+        // Count the instructions of each opcode.
+        let mut histogram = OpcodeHistogram::default();
+        for instruction in &instructions {
+            *histogram.counts.entry(instruction.opcode()).or_default() += 1;
+        }
+        Ok(histogram)
+    }
+}
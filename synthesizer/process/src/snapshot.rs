@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A snapshot of a program and the external programs it (transitively) imports.
+/// Note: The snapshot pins the stacks at the time it was taken, so executing against the snapshot
+/// is unaffected by programs that are later added to the process.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ProcessSnapshot<N: Network> {
+    /// The mapping of program IDs to stacks.
+    stacks: IndexMap<ProgramID<N>, Arc<Stack<N>>>,
+}
+
+impl<N: Network> ProcessSnapshot<N> {
+    /// Returns the program IDs in the snapshot.
+    pub fn program_ids(&self) -> impl '_ + ExactSizeIterator<Item = &ProgramID<N>> {
+        self.stacks.keys()
+    }
+
+    /// Returns `true` if the snapshot contains the program with the given ID.
+    pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
+        self.stacks.contains_key(program_id)
+    }
+
+    /// Returns the stack for the given program ID.
+    pub fn get_stack(&self, program_id: &ProgramID<N>) -> Result<&Arc<Stack<N>>> {
+        self.stacks.get(program_id).ok_or_else(|| anyhow!("Program '{program_id}' does not exist in the snapshot"))
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns a snapshot of the given program and the external programs it (transitively) imports.
+    pub fn snapshot(&self, program_id: &ProgramID<N>) -> Result<ProcessSnapshot<N>> {
+        // Initialize the stacks with the given program.
+        let mut stacks = IndexMap::new();
+        stacks.insert(*program_id, self.get_stack(program_id)?.clone());
+
+        // Add the external stacks, in the order they are discovered.
+        let mut index = 0;
+        while let Some((_, stack)) = stacks.get_index(index) {
+            let stack = stack.clone();
+            for import_id in stack.program().imports().keys() {
+                if !stacks.contains_key(import_id) {
+                    stacks.insert(*import_id, stack.get_external_stack(import_id)?.clone());
+                }
+            }
+            index += 1;
+        }
+
+        Ok(ProcessSnapshot { stacks })
+    }
+}
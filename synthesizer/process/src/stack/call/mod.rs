@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{stack::Address, CallStack, Registers, RegistersCall, StackEvaluate, StackExecute};
+use crate::{stack::Address, CallMetrics, CallStack, Registers, RegistersCall, StackEvaluate, StackExecute};
 use aleo_std::prelude::{finish, lap, timer};
 use console::{
     account::Field,
@@ -189,15 +189,29 @@ impl<N: Network> CallTrait<N> for Call<N> {
         // If the operator is a closure, retrieve the closure and compute the output.
         let outputs = if let Ok(closure) = substack.program().get_closure(resource) {
             lap!(timer, "Execute the closure");
+            // Retrieve the number of constraints before the closure.
+            let num_constraints = A::num_constraints();
             // Execute the closure, and load the outputs.
-            substack.execute_closure(
+            let outputs = substack.execute_closure(
                 &closure,
                 &inputs,
                 registers.call_stack(),
                 registers.signer_circuit()?,
                 registers.caller_circuit()?,
                 registers.tvk_circuit()?,
-            )?
+            )?;
+            // If the circuit is in `Execute` mode, then save the call metrics of the closure.
+            if let CallStack::Execute(_, trace) = registers.call_stack() {
+                trace.write().insert_closure_metrics(CallMetrics {
+                    program_id: *substack.program_id(),
+                    function_name: *closure.name(),
+                    num_instructions: closure.instructions().len(),
+                    num_request_constraints: 0,
+                    num_function_constraints: A::num_constraints().saturating_sub(num_constraints),
+                    num_response_constraints: 0,
+                });
+            }
+            outputs
         }
         // If the operator is a function, retrieve the function and compute the output.
         else if let Ok(function) = substack.program().get_function(resource) {
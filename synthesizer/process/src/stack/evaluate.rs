@@ -102,20 +102,50 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         &self,
         call_stack: CallStack<N>,
         caller: Option<ProgramID<N>>,
+    ) -> Result<Response<N>> {
+        self.evaluate_function_with_snapshot::<A>(call_stack, caller, None)
+    }
+}
+
+impl<N: Network> Stack<N> {
+    /// Evaluates a program function on the given inputs, and returns the response
+    /// along with a snapshot of the value assigned to each register of the function.
+    #[inline]
+    pub fn evaluate_function_traced<A: circuit::Aleo<Network = N>>(
+        &self,
+        call_stack: CallStack<N>,
+        caller: Option<ProgramID<N>>,
+    ) -> Result<(Response<N>, IndexMap<Register<N>, Value<N>>)> {
+        // Initialize the snapshot.
+        let mut snapshot = IndexMap::new();
+        // Evaluate the function.
+        let response = self.evaluate_function_with_snapshot::<A>(call_stack, caller, Some(&mut snapshot))?;
+        Ok((response, snapshot))
+    }
+
+    /// Evaluates a program function on the given inputs.
+    /// If a snapshot is given, the value assigned to each register of the function is recorded in it.
+    #[inline]
+    fn evaluate_function_with_snapshot<A: circuit::Aleo<Network = N>>(
+        &self,
+        call_stack: CallStack<N>,
+        caller: Option<ProgramID<N>>,
+        mut snapshot: Option<&mut IndexMap<Register<N>, Value<N>>>,
     ) -> Result<Response<N>> {
         let timer = timer!("Stack::evaluate_function");
 
         // Retrieve the next request, based on the call stack mode.
         let (request, call_stack) = match &call_stack {
-            CallStack::Evaluate(authorization) => (authorization.next()?, call_stack),
+            CallStack::Evaluate(authorization, _) => (authorization.next()?, call_stack),
             // If the evaluation is performed in the `Execute` mode, create a new `Evaluate` mode.
             // This is done to ensure that evaluation during execution is performed consistently.
+            // Note: The events are derived from the transitions of the trace, and are discarded here.
             CallStack::Execute(authorization, _) => {
                 // Note: We need to replicate the authorization, so that 'execute' can call 'authorization.next()?'.
                 // This way, the authorization remains unmodified in this 'evaluate' scope.
                 let authorization = authorization.replicate();
                 let request = authorization.next()?;
-                let call_stack = CallStack::Evaluate(authorization);
+                let call_stack = CallStack::Evaluate(authorization, Events::default());
                 (request, call_stack)
             }
             _ => bail!("Illegal operation: call stack must be `Evaluate` or `Execute` in `evaluate_function`."),
@@ -129,6 +159,13 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
             N::ID,
             request.network_id()
         );
+        // Ensure the request targets this program.
+        ensure!(
+            request.program_id() == self.program_id(),
+            "Request targets a different program. Expected '{}', but found '{}'",
+            self.program_id(),
+            request.program_id()
+        );
 
         // Retrieve the function, inputs, and transition view key.
         let function = self.get_function(request.function_name())?;
@@ -165,19 +202,29 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         lap!(timer, "Initialize the registers");
 
         // Ensure the request is well-formed.
-        ensure!(request.verify(&function.input_types(), is_root), "Request is invalid");
+        if let Err(error) = request.verify_detailed(&function.input_types(), is_root) {
+            bail!("Request is invalid - {error}")
+        }
         lap!(timer, "Verify the request");
 
         // Store the inputs.
         function.inputs().iter().map(|i| i.register()).zip_eq(inputs).try_for_each(|(register, input)| {
+            // Record the input value in the snapshot, if one is given.
+            if let Some(snapshot) = snapshot.as_deref_mut() {
+                snapshot.insert(register.clone(), input.clone());
+            }
             // Assign the input value to the register.
             registers.store(self, register, input.clone())
         })?;
         lap!(timer, "Store the inputs");
 
+        // Compute the registers to release after each instruction.
+        let releases = Self::register_releases(function);
+        lap!(timer, "Compute the register releases");
+
         // Evaluate the instructions.
         // Note: We handle the `call` instruction separately, as it requires special handling.
-        for instruction in function.instructions() {
+        for (instruction, releases) in function.instructions().iter().zip_eq(releases) {
             // Evaluate the instruction.
             let result = match instruction {
                 // If the instruction is a `call` instruction, we need to handle it separately.
@@ -189,6 +236,18 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
             if let Err(error) = result {
                 bail!("Failed to evaluate instruction ({instruction}): {error}");
             }
+            // Record the value of each destination register in the snapshot, if one is given.
+            if let Some(snapshot) = snapshot.as_deref_mut() {
+                for destination in instruction.destinations() {
+                    let value = registers.load(self, &Operand::Register(destination.clone()))?;
+                    snapshot.insert(destination, value);
+                }
+            }
+            // Record the peak number of live registers.
+            #[cfg(test)]
+            crate::PEAK_LIVE_REGISTERS.with(|peak| peak.set(peak.get().max(registers.num_live_registers())));
+            // Release the registers that are no longer referenced.
+            releases.into_iter().for_each(|locator| registers.release(locator));
         }
         lap!(timer, "Evaluate the instructions");
 
@@ -243,9 +302,48 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
             outputs,
             &function.output_types(),
             &output_registers,
-        );
+        )?;
+
+        // If the call stack is in evaluate mode, collect the public outputs of the function as events.
+        if let CallStack::Evaluate(_, events) = registers.call_stack() {
+            events.write().extend(Event::from_outputs(
+                self.program_id(),
+                function.name(),
+                response.outputs(),
+                &function.output_types(),
+            ));
+        }
         finish!(timer);
 
-        response
+        Ok(response)
+    }
+
+    /// Returns the register locators to release after each instruction of the given function,
+    /// which are the registers that are not referenced by any later instruction or by an output.
+    fn register_releases(function: &Function<N>) -> Vec<Vec<u64>> {
+        // Compute the index of the last instruction that references each register.
+        let mut last_uses = IndexMap::new();
+        for (index, instruction) in function.instructions().iter().enumerate() {
+            for operand in instruction.operands() {
+                if let Operand::Register(register) = operand {
+                    last_uses.insert(register.locator(), index);
+                }
+            }
+            for destination in instruction.destinations() {
+                last_uses.insert(destination.locator(), index);
+            }
+        }
+        // Retain the registers that are referenced by an output.
+        for output in function.outputs() {
+            if let Operand::Register(register) = output.operand() {
+                last_uses.shift_remove(&register.locator());
+            }
+        }
+        // Group the registers by the instruction after which they are released.
+        let mut releases = vec![Vec::new(); function.instructions().len()];
+        for (locator, index) in last_uses {
+            releases[index].push(locator);
+        }
+        releases
     }
 }
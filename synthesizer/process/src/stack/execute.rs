@@ -166,6 +166,13 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             N::ID,
             console_request.network_id()
         );
+        // Ensure the request targets this program.
+        ensure!(
+            console_request.program_id() == self.program_id(),
+            "Request targets a different program. Expected '{}', but found '{}'",
+            self.program_id(),
+            console_request.program_id()
+        );
 
         // We can only have a root_tvk if this request was called by another request
         ensure!(console_caller.is_some() == root_tvk.is_some());
@@ -204,7 +211,9 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         lap!(timer, "Verify the input types");
 
         // Ensure the request is well-formed.
-        ensure!(console_request.verify(&input_types, console_is_root), "Request is invalid");
+        if let Err(error) = console_request.verify_detailed(&input_types, console_is_root) {
+            bail!("Request is invalid - {error}")
+        }
         lap!(timer, "Verify the console request");
 
         // Initialize the registers.
@@ -284,7 +293,9 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         // Execute the instructions.
         for instruction in function.instructions() {
             // If the circuit is in execute mode, then evaluate the instructions.
-            if let CallStack::Execute(..) = registers.call_stack() {
+            if let CallStack::Execute(_, trace) = registers.call_stack() {
+                // Ensure the deadline of the execution has not passed.
+                trace.read().check_deadline()?;
                 // Evaluate the instruction.
                 let result = match instruction {
                     // If the instruction is a `call` instruction, we need to handle it separately.
@@ -310,6 +321,19 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 bail!("Failed to execute instruction ({instruction}): {error}");
             }
 
+            // In debug mode, if the circuit is in execute mode, ensure the evaluated and executed values of the
+            // destination registers match, to catch a mismatch between the console and circuit semantics.
+            #[cfg(debug_assertions)]
+            if let CallStack::Execute(..) = registers.call_stack() {
+                for destination in instruction.destinations() {
+                    let operand = Operand::Register(destination);
+                    ensure!(
+                        registers.load(self, &operand)? == registers.load_circuit(self, &operand)?.eject_value(),
+                        "The evaluated and executed values of '{operand}' do not match ({instruction})"
+                    );
+                }
+            }
+
             // If the instruction was a function call, then set the tracker to `true`.
             if let Instruction::Call(call) = instruction {
                 // Check if the call is a function call.
@@ -514,20 +538,34 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 
 impl<N: Network> Stack<N> {
     /// Prints the current state of the circuit.
+    /// Note: The log is colored, unless color is disabled with `Process::set_log_color`,
+    /// or by `colored` (i.e. if `NO_COLOR` is set, or if stdout is not a terminal).
     #[cfg(debug_assertions)]
     pub(crate) fn log_circuit<A: circuit::Aleo<Network = N>, S: Into<String>>(scope: S) {
+        let color = crate::execute::LOG_COLOR.load(std::sync::atomic::Ordering::Relaxed);
+        println!("{}", Self::format_circuit_log::<A, _>(scope, color));
+    }
+
+    /// Returns the log of the current state of the circuit, with or without color.
+    #[cfg(debug_assertions)]
+    pub(crate) fn format_circuit_log<A: circuit::Aleo<Network = N>, S: Into<String>>(scope: S, color: bool) -> String {
         use colored::Colorize;
 
-        // Determine if the circuit is satisfied.
-        let is_satisfied = if A::is_satisfied() { "✅".green() } else { "❌".red() };
+        // Pad the scope, before it is colored, so the escape codes are not counted in the width.
+        let scope = format!("{:width$}", scope.into(), width = 20);
+        // Determine if the circuit is satisfied, and prepare the scope.
+        let (is_satisfied, scope) = match (color, A::is_satisfied()) {
+            (true, true) => ("✅".green().to_string(), scope.bold().to_string()),
+            (true, false) => ("❌".red().to_string(), scope.bold().to_string()),
+            (false, true) => ("✅".to_string(), scope),
+            (false, false) => ("❌".to_string(), scope),
+        };
         // Determine the count.
         let (num_constant, num_public, num_private, num_constraints, num_nonzeros) = A::count();
 
-        // Print the log.
-        println!(
-            "{is_satisfied} {:width$} (Constant: {num_constant}, Public: {num_public}, Private: {num_private}, Constraints: {num_constraints}, NonZeros: {num_nonzeros:?})",
-            scope.into().bold(),
-            width = 20
-        );
+        // Return the log.
+        format!(
+            "{is_satisfied} {scope} (Constant: {num_constant}, Public: {num_public}, Private: {num_private}, Constraints: {num_constraints}, NonZeros: {num_nonzeros:?})"
+        )
     }
 }
@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The policy of opcodes that a stack is permitted to evaluate or execute.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum StackPolicy {
+    /// All opcodes are permitted.
+    #[default]
+    Permissive,
+    /// Only the given opcodes are permitted.
+    Restricted(IndexSet<&'static str>),
+}
+
+impl StackPolicy {
+    /// Initializes a new policy that only permits the given opcodes.
+    pub fn restricted(opcodes: impl IntoIterator<Item = Opcode>) -> Self {
+        Self::Restricted(opcodes.into_iter().map(|opcode| *opcode).collect())
+    }
+
+    /// Ensures the given opcode is permitted by the policy.
+    pub fn check_opcode(&self, opcode: Opcode) -> Result<()> {
+        match self {
+            Self::Permissive => Ok(()),
+            Self::Restricted(opcodes) => match opcodes.contains(*opcode) {
+                true => Ok(()),
+                false => bail!("Opcode {opcode} not permitted in sandbox mode"),
+            },
+        }
+    }
+}
+
+impl<N: Network> Stack<N> {
+    /// Ensures the program, and the programs it imports, only use opcodes permitted by the given policy.
+    pub fn check_policy(&self, policy: &StackPolicy) -> Result<()> {
+        // If the policy is permissive, return early.
+        if let StackPolicy::Permissive = policy {
+            return Ok(());
+        }
+        // Ensure the closures only use permitted opcodes.
+        for closure in self.program.closures().values() {
+            closure.instructions().iter().try_for_each(|instruction| policy.check_opcode(instruction.opcode()))?;
+        }
+        // Ensure the functions (and their finalize logic) only use permitted opcodes.
+        for function in self.program.functions().values() {
+            function.instructions().iter().try_for_each(|instruction| policy.check_opcode(instruction.opcode()))?;
+            if let Some(finalize) = function.finalize_logic() {
+                finalize.commands().iter().try_for_each(|command| policy.check_opcode(command.opcode()))?;
+            }
+        }
+        // Ensure the imported programs only use permitted opcodes.
+        self.external_stacks.values().try_for_each(|external_stack| external_stack.check_policy(policy))
+    }
+}
@@ -37,6 +37,12 @@ use synthesizer_program::{
 
 use indexmap::IndexMap;
 
+#[cfg(test)]
+thread_local! {
+    /// The peak number of live console registers, updated during function evaluation.
+    pub(crate) static PEAK_LIVE_REGISTERS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
 #[derive(Clone)]
 pub struct Registers<N: Network, A: circuit::Aleo<Network = N>> {
     /// The current call stack.
@@ -47,6 +53,8 @@ pub struct Registers<N: Network, A: circuit::Aleo<Network = N>> {
     console_registers: IndexMap<u64, Value<N>>,
     /// The mapping of assigned circuit registers to their values.
     circuit_registers: IndexMap<u64, circuit::Value<A>>,
+    /// The number of console registers released after their last use.
+    num_released: u64,
     /// The transition signer.
     signer: Option<Address<N>>,
     /// The transition signer, as a circuit.
@@ -74,6 +82,7 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
             register_types,
             console_registers: IndexMap::new(),
             circuit_registers: IndexMap::new(),
+            num_released: 0,
             signer: None,
             signer_circuit: None,
             root_tvk: None,
@@ -85,6 +94,21 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
         }
     }
 
+    /// Releases the console value of the given register locator, once it is no longer referenced.
+    /// Note: The register can not be reassigned, as register assignments are monotonically increasing.
+    #[inline]
+    pub fn release(&mut self, locator: u64) {
+        if self.console_registers.shift_remove(&locator).is_some() {
+            self.num_released += 1;
+        }
+    }
+
+    /// Returns the number of console registers that currently hold a value.
+    #[inline]
+    pub fn num_live_registers(&self) -> usize {
+        self.console_registers.len()
+    }
+
     /// Ensure the console and circuit registers match.
     #[inline]
     pub fn ensure_console_and_circuit_registers_match(&self) -> Result<()> {
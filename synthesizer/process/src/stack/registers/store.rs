@@ -31,7 +31,7 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersStore<N> for Registers<
         match register {
             Register::Locator(locator) => {
                 // Ensure the register assignments are monotonically increasing.
-                let expected_locator = self.console_registers.len() as u64;
+                let expected_locator = self.console_registers.len() as u64 + self.num_released;
                 ensure!(expected_locator == *locator, "Out-of-order write operation at '{register}'");
                 // Ensure the register does not already exist.
                 ensure!(
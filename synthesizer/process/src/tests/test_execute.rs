@@ -14,19 +14,23 @@
 
 use crate::{
     traits::{StackEvaluate, StackExecute},
+    Authorization,
     CallStack,
+    Event,
     Process,
+    ProcessError,
     Stack,
+    StackPolicy,
     Trace,
 };
 use circuit::{network::AleoV0, Aleo};
 use console::{
     account::{Address, PrivateKey, ViewKey},
     network::{prelude::*, MainnetV0},
-    program::{Identifier, Literal, Plaintext, ProgramID, Record, Value},
-    types::{Field, U64},
+    program::{compute_function_id, Identifier, Literal, Plaintext, ProgramID, Record, Register, Request, Value},
+    types::{Field, U16, U64},
 };
-use ledger_block::{Fee, Transaction};
+use ledger_block::{Execution, Fee, Output, Transaction, Transition};
 use ledger_query::Query;
 use ledger_store::{
     helpers::memory::{BlockMemory, FinalizeMemory},
@@ -35,7 +39,7 @@ use ledger_store::{
     FinalizeStorage,
     FinalizeStore,
 };
-use synthesizer_program::{FinalizeGlobalState, FinalizeStoreTrait, Program, StackProgram};
+use synthesizer_program::{FinalizeGlobalState, FinalizeStoreTrait, Opcode, Operand, Program, StackProgram};
 use synthesizer_snark::UniversalSRS;
 
 use indexmap::IndexMap;
@@ -155,6 +159,128 @@ function foo:
     assert_eq!(expected, candidate[0]);
 }
 
+#[test]
+fn test_process_execute_rejects_mismatched_program_id() {
+    // Initialize two programs with the same function signature.
+    let program_a = Program::<CurrentNetwork>::from_str(
+        r"
+program program_a.aleo;
+
+function foo:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;
+",
+    )
+    .unwrap();
+    let program_b = Program::<CurrentNetwork>::from_str(
+        r"
+program program_b.aleo;
+
+function foo:
+    input r0 as field.public;
+    input r1 as field.private;
+    mul r0 r1 into r2;
+    output r2 as field.private;
+",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program_a);
+    process.add_program(&program_b).unwrap();
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize a call to program A.
+    let inputs = [
+        Value::<CurrentNetwork>::Plaintext(Plaintext::from_str("2field").unwrap()),
+        Value::Plaintext(Plaintext::from_str("3field").unwrap()),
+    ];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program_a.id(), "foo", inputs.iter(), rng).unwrap();
+
+    // Ensure the request is rejected by the stack of program B.
+    let stack_b = process.get_stack(program_b.id()).unwrap();
+    let error = stack_b
+        .evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization.replicate()).unwrap(), None)
+        .unwrap_err();
+    assert!(error.to_string().contains("Request targets a different program"), "{error}");
+    let trace = Arc::new(RwLock::new(Trace::new()));
+    let error = stack_b
+        .execute_function::<CurrentAleo, _>(
+            CallStack::execute(authorization.replicate(), trace).unwrap(),
+            None,
+            None,
+            rng,
+        )
+        .unwrap_err();
+    assert!(error.to_string().contains("Request targets a different program"), "{error}");
+
+    // Ensure the request is accepted by the stack of program A.
+    let stack_a = process.get_stack(program_a.id()).unwrap();
+    let response = stack_a.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization).unwrap(), None).unwrap();
+    assert_eq!(response.outputs(), [Value::Plaintext(Plaintext::from_str("5field").unwrap())]);
+}
+
+#[test]
+fn test_process_evaluate_with_policy() {
+    // Initialize a new program that calls a closure.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program sandbox.aleo;
+
+closure twice:
+    input r0 as field;
+    add r0 r0 into r1;
+    output r1 as field;
+
+function foo:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;
+
+function bar:
+    input r0 as field.private;
+    call twice r0 into r1;
+    output r1 as field.private;
+",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize a call to `bar`.
+    let inputs = [Value::<CurrentNetwork>::Plaintext(Plaintext::from_str("3field").unwrap())];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "bar", inputs.iter(), rng).unwrap();
+
+    // Ensure the program is rejected under a policy that only permits arithmetic.
+    let policy = StackPolicy::restricted([Opcode::Literal("add"), Opcode::Literal("mul")]);
+    let error = process.evaluate_with_policy::<CurrentAleo>(authorization.replicate(), &policy).unwrap_err();
+    assert_eq!(error.to_string(), "Opcode call not permitted in sandbox mode");
+
+    // Ensure the program is allowed under the default policy.
+    let response = process.evaluate_with_policy::<CurrentAleo>(authorization, &StackPolicy::default()).unwrap();
+    assert_eq!(response.outputs(), [Value::Plaintext(Plaintext::from_str("6field").unwrap())]);
+
+    // Ensure the program is allowed under a policy that also permits `call`.
+    let policy = StackPolicy::restricted([Opcode::Literal("add"), Opcode::Call]);
+    let stack = process.get_stack(program.id()).unwrap();
+    stack.check_policy(&policy).unwrap();
+}
+
 #[test]
 fn test_program_evaluate_struct_and_function() {
     // Initialize a new program.
@@ -404,6 +530,115 @@ output r4 as field.private;",
     assert_eq!(r4, candidate[2]);
 }
 
+#[test]
+fn test_process_output_by_register() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program example_output.aleo;
+
+closure execute:
+    input r0 as field;
+    input r1 as field;
+    add r0 r1 into r2;
+    add r0 r2 into r3;
+    add r2 r3 into r4;
+    output r4 as field;
+    output r3 as field;
+    output r2 as field;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.public;
+    call execute r0 r1 into r2 r3 r4;
+    output r2 as field.private;
+    output r3 as field.private;
+    output r4 as field.private;",
+    )
+    .unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Declare the expected output values.
+    let expected = ["19field", "11field", "8field"].map(|output| Value::<CurrentNetwork>::from_str(output).unwrap());
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let inputs = [Value::<CurrentNetwork>::from_str("3field").unwrap(), Value::from_str("5field").unwrap()];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+
+    // Evaluate the function call.
+    let response = process.evaluate::<CurrentAleo>(authorization).unwrap();
+
+    // Ensure the outputs can be retrieved by position.
+    assert_eq!(response.output(1), Some(&expected[1]));
+    assert_eq!(response.output(3), None);
+
+    // Ensure the outputs can be retrieved by register.
+    for (index, expected) in expected.iter().enumerate() {
+        let register = Register::Locator(2 + index as u64);
+        assert_eq!(process.output_by_register(program.id(), function_name, &response, &register).unwrap(), expected);
+    }
+    // Ensure a register that is not an output is rejected.
+    assert!(process.output_by_register(program.id(), function_name, &response, &Register::Locator(0)).is_err());
+}
+
+#[test]
+fn test_process_evaluate_closure() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program example_closure.aleo;
+
+closure execute:
+    input r0 as field;
+    input r1 as field;
+    add r0 r1 into r2;
+    add r0 r2 into r3;
+    add r2 r3 into r4;
+    output r4 as field;
+    output r3 as field;
+    output r2 as field;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.public;
+    call execute r0 r1 into r2 r3 r4;
+    output r2 as field.private;
+    output r3 as field.private;
+    output r4 as field.private;",
+    )
+    .unwrap();
+
+    // Declare the input values.
+    let inputs = [Value::<CurrentNetwork>::from_str("3field").unwrap(), Value::from_str("5field").unwrap()];
+    // Declare the expected output values.
+    let expected = ["19field", "11field", "8field"].map(|output| Value::<CurrentNetwork>::from_str(output).unwrap());
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Ensure the closure can be evaluated directly, without a request.
+    let outputs = process.evaluate_closure::<CurrentAleo>(program.id(), "execute", &inputs).unwrap();
+    assert_eq!(outputs, expected);
+
+    // Ensure the wrong number of inputs is rejected.
+    assert!(process.evaluate_closure::<CurrentAleo>(program.id(), "execute", &inputs[..1]).is_err());
+    // Ensure a function can not be evaluated as a closure.
+    assert!(process.evaluate_closure::<CurrentAleo>(program.id(), "compute", &inputs).is_err());
+}
+
 #[test]
 fn test_program_evaluate_cast() {
     // Initialize a new program.
@@ -571,6 +806,51 @@ function hello_world:
     process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, &mut TestRng::default()).unwrap();
 }
 
+#[test]
+fn test_process_prover_key_is_cached() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r#"program testing.aleo;
+
+function hello_world:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;
+"#,
+    )
+    .unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("hello_world").unwrap();
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Ensure the proving key is not cached yet.
+    assert!(!process.get_stack(program.id()).unwrap().contains_proving_key(&function_name));
+
+    // Retrieve the proving key, which synthesizes it.
+    let (proving_key, _) = process.prover_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+    // Retrieve the proving key again.
+    let (candidate, _) = process.prover_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+    // Ensure the cached proving key is reused.
+    assert!(std::ptr::eq(proving_key.deref(), candidate.deref()));
+
+    // Execute the function.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let inputs = [Value::<CurrentNetwork>::from_str("3u32").unwrap(), Value::from_str("5u32").unwrap()];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+    process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    // Ensure the execution did not replace the cached proving key.
+    let candidate = process.get_proving_key(program.id(), function_name).unwrap();
+    assert!(std::ptr::eq(proving_key.deref(), candidate.deref()));
+}
+
 #[test]
 fn test_process_multirecords() {
     // Initialize a new program.
@@ -1039,53 +1319,313 @@ function compute:
 }
 
 #[test]
-fn test_process_execute_call_external_function() {
+fn test_process_execute_reveals_public_inputs() {
     // Initialize a new program.
-    let (string, program0) = Program::<CurrentNetwork>::parse(
+    let (string, program) = Program::<CurrentNetwork>::parse(
         r"
 program token.aleo;
 
-record token:
-    owner as address.private;
-    amount as u64.private;
+function compute:
+    input r0 as field.private;
+    input r1 as field.public;
+    add r0 r1 into r2;
+    output r2 as field.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
 
-function mint:
-    input r0 as address.private;
-    input r1 as u64.private;
-    cast r0 r1 into r2 as token.record;
-    output r2 as token.record;
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
 
-function produce_magic_number:
-    add 1234u64 0u64 into r0;
-    output r0 as u64.private;
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
 
-function check_magic_number:
-    input r0 as u64.private;
-    assert.eq r0 1234u64;
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
 
-function noop:
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
 
-function transfer:
-    input r0 as token.record;
-    input r1 as address.private;
-    input r2 as u64.private;
-    sub r0.amount r2 into r3;
-    cast r1 r2 into r4 as token.record;
-    cast r0.owner r3 into r5 as token.record;
+    // Declare the input values.
+    let r0 = Value::<CurrentNetwork>::from_str("3field").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("5field").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+
+    // Execute the request.
+    let (_response, trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(trace.transitions().len(), 1);
+
+    // Ensure the public input is revealed in the transition, and the private input is not.
+    let transition = &trace.transitions()[0];
+    let public_inputs = transition.public_inputs().collect::<Vec<_>>();
+    assert_eq!(public_inputs, vec![&Plaintext::from_str("5field").unwrap()]);
+    assert!(transition.inputs()[0].public_plaintext().is_none());
+    assert_eq!(transition.inputs()[1].public_plaintext(), Some(&Plaintext::from_str("5field").unwrap()));
+}
+
+#[test]
+fn test_transition_from_outputs() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    token_amount as u64.private;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.public;
+    input r2 as token.record;
+    add r0 r1 into r3;
+    cast r2.owner r2.token_amount into r4 as token.record;
     output r4 as token.record;
-    output r5 as token.record;",
+    output r3 as field.private;
+    output r3 as field.public;",
     )
     .unwrap();
     assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
 
-    // Construct the process.
-    let mut process = crate::test_helpers::sample_process(&program0);
-    // Initialize another program.
-    let (string, program1) = Program::<CurrentNetwork>::parse(
-        r"
-import token.aleo;
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
 
-program wallet.aleo;
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the input values.
+    let r0 = Value::<CurrentNetwork>::from_str("3field").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("5field").unwrap();
+    let r2 = Value::<CurrentNetwork>::from_str(&format!(
+        "{{ owner: {caller}.private, token_amount: 100u64.private, _nonce: 0group.public }}"
+    ))
+    .unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1, r2].iter(), rng)
+        .unwrap();
+    let request = authorization.peek_next().unwrap();
+    // Retrieve the authorized transition.
+    let transitions = authorization.transitions();
+    assert_eq!(transitions.len(), 1);
+    let expected = transitions.values().next().unwrap();
+
+    // Assemble the transition from the request and the computed outputs.
+    let candidate = ledger_block::Transition::from_outputs(&request, expected.outputs().to_vec()).unwrap();
+    assert_eq!(expected, &candidate);
+    assert_eq!(expected.id(), candidate.id());
+
+    // Ensure a tampered output is rejected.
+    let mut outputs = expected.outputs().to_vec();
+    outputs[2] = ledger_block::Output::Public(Field::rand(rng), Some(Plaintext::from_str("8field").unwrap()));
+    assert!(ledger_block::Transition::from_outputs(&request, outputs).is_err());
+}
+
+#[test]
+fn test_transition_from_streaming() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    token_amount as u64.private;
+
+function compute:
+    input r0 as field.private;
+    input r1 as token.record;
+    add r0 r0 into r2;
+    cast r1.owner r1.token_amount into r3 as token.record;
+    cast r1.owner r1.token_amount into r4 as token.record;
+    output r3 as token.record;
+    output r2 as field.private;
+    output r4 as token.record;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+    // Retrieve the function.
+    let function = program.get_function(&function_name).unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the input values.
+    let r0 = Value::<CurrentNetwork>::from_str("3field").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str(&format!(
+        "{{ owner: {caller}.private, token_amount: 100u64.private, _nonce: 0group.public }}"
+    ))
+    .unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+    let request = authorization.peek_next().unwrap();
+    // Retrieve the authorized transition.
+    let expected = authorization.transitions().values().next().unwrap().clone();
+
+    // Evaluate the function call.
+    let response = process.evaluate::<CurrentAleo>(authorization).unwrap();
+    // Retrieve the output registers.
+    let output_registers = function
+        .outputs()
+        .iter()
+        .map(|output| match output.operand() {
+            Operand::Register(register) => Some(register.clone()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    // Construct the transition, streaming each output.
+    let mut streamed = vec![];
+    let candidate = ledger_block::Transition::from_streaming(
+        &request,
+        &response,
+        &function.output_types(),
+        &output_registers,
+        |output| {
+            streamed.push(output.clone());
+            Ok(())
+        },
+    )
+    .unwrap();
+
+    // Ensure the streamed outputs match the collected outputs.
+    assert_eq!(expected, candidate);
+    assert_eq!(streamed, candidate.outputs());
+
+    // Ensure an error in the callback stops the construction after the failing output.
+    let mut num_streamed = 0;
+    let result = ledger_block::Transition::from_streaming(
+        &request,
+        &response,
+        &function.output_types(),
+        &output_registers,
+        |_| {
+            num_streamed += 1;
+            ensure!(num_streamed < 2, "Failed to flush the output");
+            Ok(())
+        },
+    );
+    assert!(result.is_err());
+    assert_eq!(num_streamed, 2);
+}
+
+#[test]
+fn test_process_record_types() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    balance as u64.private;
+    token_amount as u64.public;
+
+function mint:
+    input r0 as u64.private;
+    cast self.caller r0 r0 into r1 as token.record;
+    output r1 as token.record;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Retrieve the record types.
+    let record_types = process.record_types(program.id()).unwrap();
+    assert_eq!(record_types.len(), 1);
+    let record_type = record_types.get(&Identifier::from_str("token").unwrap()).unwrap();
+
+    // Ensure the owner and entries are returned by name and type.
+    assert_eq!(record_type.owner().to_string(), "private");
+    let entries = record_type
+        .entries()
+        .iter()
+        .map(|(name, entry_type)| (name.to_string(), entry_type.to_string()))
+        .collect::<Vec<_>>();
+    assert_eq!(entries, [
+        ("balance".to_string(), "u64.private".to_string()),
+        ("token_amount".to_string(), "u64.public".to_string())
+    ]);
+
+    // Ensure an unknown program is rejected.
+    assert!(process.record_types("unknown.aleo").is_err());
+}
+
+#[test]
+fn test_process_execute_call_external_function() {
+    // Initialize a new program.
+    let (string, program0) = Program::<CurrentNetwork>::parse(
+        r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    cast r0 r1 into r2 as token.record;
+    output r2 as token.record;
+
+function produce_magic_number:
+    add 1234u64 0u64 into r0;
+    output r0 as u64.private;
+
+function check_magic_number:
+    input r0 as u64.private;
+    assert.eq r0 1234u64;
+
+function noop:
+
+function transfer:
+    input r0 as token.record;
+    input r1 as address.private;
+    input r2 as u64.private;
+    sub r0.amount r2 into r3;
+    cast r1 r2 into r4 as token.record;
+    cast r0.owner r3 into r5 as token.record;
+    output r4 as token.record;
+    output r5 as token.record;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+    // Initialize another program.
+    let (string, program1) = Program::<CurrentNetwork>::parse(
+        r"
+import token.aleo;
+
+program wallet.aleo;
 
 function transfer:
     input r0 as token.aleo/token.record;
@@ -2645,3 +3185,1988 @@ fn test_program_exceeding_transaction_spend_limit() {
     let result = Stack::initialize(&process, &program);
     assert!(result.is_err());
 }
+
+#[test]
+fn test_process_num_public_inputs() {
+    // Initialize a new program.
+    let (string, program0) = Program::<CurrentNetwork>::parse(
+        r"
+program child.aleo;
+
+function twice:
+    input r0 as field.public;
+    add r0 r0 into r1;
+    output r1 as field.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Initialize another program.
+    let (string, program1) = Program::<CurrentNetwork>::parse(
+        r"
+import child.aleo;
+
+program parent.aleo;
+
+record token:
+    owner as address.private;
+    token_amount as u64.private;
+
+function spend:
+    input r0 as token.record;
+    input r1 as field.public;
+    call child.aleo/twice r1 into r2;
+    cast r0.owner r0.token_amount into r3 as token.record;
+    output r3 as token.record;
+    output r2 as field.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("spend").unwrap();
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the input values.
+    let r0 = Value::<CurrentNetwork>::from_str(&format!(
+        "{{ owner: {caller}.private, token_amount: 100u64.private, _nonce: 0group.public }}"
+    ))
+    .unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("3field").unwrap();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+    process.add_program(&program1).unwrap();
+
+    // Compute the number of public inputs from the function signature.
+    // Note: This is 5 for the header, 3 for the inputs, 3 for `is_root` and `parent`, 3 for the call, and 3 for the outputs.
+    let num_public_inputs = process.num_public_inputs(program1.id(), function_name).unwrap();
+    assert_eq!(num_public_inputs, 17);
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 2);
+    let request = authorization.next().unwrap();
+
+    // Synthesize the circuit, saving the assignments.
+    let assignments = crate::Assignments::<CurrentNetwork>::default();
+    let call_stack = CallStack::PackageRun(vec![request], caller_private_key, assignments.clone());
+    let stack = process.get_stack(program1.id()).unwrap();
+    stack.execute_function::<CurrentAleo, _>(call_stack, None, None, rng).unwrap();
+
+    // Ensure the count matches the number of public variables in the circuit of the root function.
+    let assignments = assignments.read();
+    assert_eq!(assignments.len(), 2);
+    let (assignment, _) = assignments.last().unwrap();
+    assert_eq!(assignment.num_public(), num_public_inputs as u64);
+
+    // Ensure an unknown function is rejected.
+    assert!(process.num_public_inputs(program1.id(), "unknown").is_err());
+}
+
+#[test]
+fn test_process_execute_from_fields() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program fields.aleo;
+
+struct message:
+    first as field;
+    second as u64;
+
+record token:
+    owner as address.private;
+    token_amount as u64.private;
+
+function compute:
+    input r0 as message.private;
+    input r1 as u64.public;
+    add r0.second r1 into r2;
+    add r0.first r0.first into r3;
+    output r2 as u64.private;
+    output r3 as field.public;
+
+function burn:
+    input r0 as token.record;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Declare the input values.
+    let inputs = [
+        Value::<CurrentNetwork>::from_str("{ first: 3field, second: 4u64 }").unwrap(),
+        Value::<CurrentNetwork>::from_str("5u64").unwrap(),
+    ];
+    // Flatten the inputs into field elements.
+    let input_fields = inputs.iter().map(|input| input.to_fields().unwrap()).collect::<Vec<_>>();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Execute the function directly.
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "compute", inputs.iter(), rng).unwrap();
+    let (expected, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+
+    // Execute the function from the field elements.
+    let (candidate, trace) = process
+        .execute_from_fields::<CurrentAleo, _>(&caller_private_key, program.id(), "compute", input_fields.clone(), rng)
+        .unwrap();
+    assert_eq!(trace.transitions().len(), 1);
+    assert_eq!(candidate.outputs(), expected.outputs());
+    assert_eq!(candidate.outputs(), [Value::from_str("9u64").unwrap(), Value::from_str("6field").unwrap()]);
+
+    // Ensure the wrong number of inputs is rejected.
+    assert!(process
+        .execute_from_fields::<CurrentAleo, _>(&caller_private_key, program.id(), "compute", vec![], rng)
+        .is_err());
+    // Ensure a record input is rejected.
+    let error = process
+        .execute_from_fields::<CurrentAleo, _>(&caller_private_key, program.id(), "burn", vec![vec![]], rng)
+        .unwrap_err();
+    assert_eq!(error.to_string(), "Cannot reconstruct a 'token.record' input from field elements");
+}
+
+#[test]
+fn test_process_evaluate_traced() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program traced.aleo;
+
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    add r0 r2 into r3;
+    add r2 r3 into r4;
+    output r4 as field.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Declare the input values.
+    let a = Value::<CurrentNetwork>::from_str("3field").unwrap();
+    let b = Value::<CurrentNetwork>::from_str("5field").unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "compute", [a.clone(), b.clone()].iter(), rng)
+        .unwrap();
+
+    // Evaluate the function, recording the registers.
+    let (response, snapshot) = process.evaluate_traced::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(response.outputs(), process.evaluate::<CurrentAleo>(authorization).unwrap().outputs());
+
+    // Ensure each register is recorded, in order.
+    let register = |locator: u64| Register::<CurrentNetwork>::Locator(locator);
+    assert_eq!(snapshot.keys().cloned().collect::<Vec<_>>(), (0..5).map(register).collect::<Vec<_>>());
+    assert_eq!(snapshot[&register(0)], a);
+    assert_eq!(snapshot[&register(1)], b);
+    // Ensure `r2 == a + b`.
+    assert_eq!(snapshot[&register(2)], Value::from_str("8field").unwrap());
+    // Ensure `r3 == a + r2`.
+    assert_eq!(snapshot[&register(3)], Value::from_str("11field").unwrap());
+    // Ensure `r4 == r2 + r3`.
+    assert_eq!(snapshot[&register(4)], Value::from_str("19field").unwrap());
+    assert_eq!(response.outputs(), [snapshot[&register(4)].clone()]);
+}
+
+#[test]
+fn test_process_execute_rejects_unsatisfied_circuit() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program unsatisfied.aleo;
+
+function compute:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;",
+    )
+    .unwrap();
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Retrieve the stack.
+    let stack = process.get_stack(program.id()).unwrap();
+
+    // Sign a child request, where the signer commitment is bound to the given `root_tvk`.
+    let function_name = Identifier::from_str("compute").unwrap();
+    let input_types = program.get_function(&function_name).unwrap().input_types();
+    let request = Request::sign(
+        &caller_private_key,
+        *program.id(),
+        function_name,
+        [Value::<CurrentNetwork>::from_str("5u64").unwrap()].into_iter(),
+        &input_types,
+        Some(Uniform::rand(rng)),
+        false,
+        rng,
+    )
+    .unwrap();
+
+    // Execute the request under a different `root_tvk`.
+    // Note: The console request is well-formed, however the circuit fails to verify the signer commitment.
+    let trace = Arc::new(RwLock::new(Trace::new()));
+    let call_stack = CallStack::execute(Authorization::new(request), trace).unwrap();
+    let caller = Some(ProgramID::from_str("caller.aleo").unwrap());
+    let error =
+        stack.execute_function::<CurrentAleo, _>(call_stack, caller, Some(Uniform::rand(rng)), rng).unwrap_err();
+    assert!(error.to_string().contains("'unsatisfied.aleo/compute' is not satisfied on the given inputs"));
+}
+
+#[test]
+fn test_process_evaluate_matches_execute() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program arithmetic.aleo;
+
+function checked_add:
+    input r0 as u8.public;
+    input r1 as u8.private;
+    add r0 r1 into r2;
+    output r2 as u8.private;
+
+function add_wrapped:
+    input r0 as u8.public;
+    input r1 as u8.private;
+    add.w r0 r1 into r2;
+    output r2 as u8.private;
+
+function checked_sub:
+    input r0 as u8.public;
+    input r1 as u8.private;
+    sub r0 r1 into r2;
+    output r2 as u8.private;
+
+function sub_wrapped:
+    input r0 as u8.public;
+    input r1 as u8.private;
+    sub.w r0 r1 into r2;
+    output r2 as u8.private;
+
+function checked_mul:
+    input r0 as u8.public;
+    input r1 as u8.private;
+    mul r0 r1 into r2;
+    output r2 as u8.private;
+
+function mul_wrapped:
+    input r0 as u8.public;
+    input r1 as u8.private;
+    mul.w r0 r1 into r2;
+    output r2 as u8.private;",
+    )
+    .unwrap();
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Declare the inputs, including inputs that overflow or underflow.
+    let inputs = [("3u8", "5u8"), ("5u8", "3u8"), ("200u8", "100u8"), ("16u8", "16u8"), ("255u8", "255u8")];
+
+    for function_name in program.functions().keys() {
+        for (a, b) in inputs {
+            let inputs = [Value::<CurrentNetwork>::from_str(a).unwrap(), Value::from_str(b).unwrap()];
+            // Authorize the function call.
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+                .unwrap();
+
+            // Evaluate the function call.
+            // Note: A checked operation that overflows halts.
+            let evaluated = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                process.evaluate::<CurrentAleo>(authorization.replicate())
+            }));
+            let evaluated =
+                evaluated.ok().and_then(|response| response.ok()).map(|response| response.outputs().to_vec());
+
+            // Execute the function call.
+            let executed = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                process.execute::<CurrentAleo, _>(authorization, &mut TestRng::default())
+            }));
+            let executed =
+                executed.ok().and_then(|result| result.ok()).map(|(response, _)| response.outputs().to_vec());
+
+            // Ensure the evaluated and executed outputs match.
+            assert_eq!(evaluated, executed, "'{function_name}' disagrees on the inputs ({a}, {b})");
+            // Ensure the wrapped operations never halt.
+            if function_name.to_string().ends_with("_wrapped") {
+                assert!(evaluated.is_some(), "'{function_name}' halted on the inputs ({a}, {b})");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_process_output_set_root() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program outputs.aleo;
+
+function compute:
+    add 1u8 2u8 into r0;
+    mul 3u8 4u8 into r1;
+    output r0 as u8.public;
+    output r1 as u8.private;
+    output r0 as u8.constant;",
+    )
+    .unwrap();
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "compute", Vec::<Value<_>>::new().iter(), rng)
+        .unwrap();
+    // Execute the request.
+    let (_, trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(trace.transitions().len(), 1);
+
+    // Ensure the output set root matches the transition root, as the transition has no inputs.
+    let transition = &trace.transitions()[0];
+    assert_eq!(transition.outputs().len(), 3);
+    let root = ledger_block::output_set_root(transition.outputs()).unwrap();
+    assert_eq!(root, transition.to_root().unwrap());
+    // Ensure the output set root depends on the order of the outputs.
+    let mut outputs = transition.outputs().to_vec();
+    outputs.swap(0, 1);
+    assert_ne!(ledger_block::output_set_root(&outputs).unwrap(), root);
+}
+
+#[test]
+fn test_process_validate_request() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program validate.aleo;
+
+function compute:
+    input r0 as u8.public;
+    input r1 as u8.private;
+    add r0 r1 into r2;
+    output r2 as u8.private;",
+    )
+    .unwrap();
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Authorize the function call.
+    let inputs = [Value::<CurrentNetwork>::from_str("3u8").unwrap(), Value::from_str("5u8").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "compute", inputs.iter(), rng).unwrap();
+    let request = authorization.peek_next().unwrap();
+
+    // Ensure a well-formed request has no validation failures.
+    assert!(process.validate_request(&request).is_empty());
+
+    // Replace the first input with a boolean, which both mismatches its type and invalidates the signature.
+    let malformed = Request::from((
+        *request.signer(),
+        *request.network_id(),
+        *request.program_id(),
+        *request.function_name(),
+        request.input_ids().to_vec(),
+        vec![Value::from_str("true").unwrap(), request.inputs()[1].clone()],
+        *request.signature(),
+        *request.sk_tag(),
+        *request.tvk(),
+        *request.tcm(),
+        *request.scm(),
+    ));
+
+    // Ensure both failures are returned.
+    let errors = process.validate_request(&malformed);
+    assert_eq!(errors.len(), 2, "{errors:?}");
+    assert!(matches!(errors[0], ProcessError::InputType(0, _)));
+    assert_eq!(errors[1], ProcessError::InvalidSignature);
+}
+
+#[test]
+fn test_process_execute_struct_outputs() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program structs.aleo;
+
+struct point:
+    x as u8;
+    y as u8;
+
+struct segment:
+    start as point;
+    end as point;
+    label as field;
+
+function compute:
+    input r0 as u8.public;
+    input r1 as u8.private;
+    cast r0 r1 into r2 as point;
+    cast r1 r0 into r3 as point;
+    cast r2 r3 1field into r4 as segment;
+    output r2 as point.public;
+    output r4 as segment.public;
+    output r4 as segment.constant;",
+    )
+    .unwrap();
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Authorize the function call.
+    let inputs = [Value::<CurrentNetwork>::from_str("3u8").unwrap(), Value::from_str("5u8").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "compute", inputs.iter(), rng).unwrap();
+
+    // Evaluate and execute the request.
+    let evaluated = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    let (response, trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(evaluated.output_ids(), response.output_ids());
+    assert_eq!(trace.transitions().len(), 1);
+
+    // Ensure the struct outputs are public and constant plaintexts.
+    let transition = &trace.transitions()[0];
+    let outputs = transition.outputs();
+    assert_eq!(outputs.len(), 3);
+    assert!(matches!(outputs[0], Output::Public(_, Some(Plaintext::Struct(..)))));
+    assert!(matches!(outputs[1], Output::Public(_, Some(Plaintext::Struct(..)))));
+    assert!(matches!(outputs[2], Output::Constant(_, Some(Plaintext::Struct(..)))));
+
+    // Ensure the natively-computed output IDs match the output IDs computed in the circuit.
+    let function_id =
+        compute_function_id(&U16::new(CurrentNetwork::ID), program.id(), transition.function_name()).unwrap();
+    assert!(transition.verify_outputs(function_id));
+}
+
+#[test]
+fn test_transition_rejects_extra_output() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program outputs.aleo;
+
+function compute:
+    input r0 as u8.public;
+    input r1 as u8.private;
+    add r0 r1 into r2;
+    output r2 as u8.public;",
+    )
+    .unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+    // Retrieve the function.
+    let function = program.get_function(&function_name).unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let inputs = [Value::<CurrentNetwork>::from_str("3u8").unwrap(), Value::from_str("5u8").unwrap()];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+    let request = authorization.peek_next().unwrap();
+
+    // Evaluate the function call.
+    let response = process.evaluate::<CurrentAleo>(authorization).unwrap();
+    // Retrieve the output types and registers.
+    let output_types = function.output_types();
+    let output_registers = function
+        .outputs()
+        .iter()
+        .map(|output| match output.operand() {
+            Operand::Register(register) => Some(register.clone()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    // Ensure the transition is constructed from the response.
+    ledger_block::Transition::from(&request, &response, &output_types, &output_registers).unwrap();
+
+    // Add one output too many to the response.
+    let mut output_ids = response.output_ids().to_vec();
+    output_ids.push(output_ids[0].clone());
+    let mut outputs = response.outputs().to_vec();
+    outputs.push(outputs[0].clone());
+
+    // Ensure the transition rejects the extra output.
+    let extended = console::program::Response::from((output_ids.clone(), outputs.clone()));
+    let error = ledger_block::Transition::from(&request, &extended, &output_types, &output_registers).unwrap_err();
+    assert_eq!(error.to_string(), "Output index 1 is out of bounds, as the function declares 1 outputs");
+
+    // Ensure the last output index is reported, when there are several extra outputs.
+    let extended = console::program::Response::from((
+        [output_ids.clone(), vec![output_ids[0].clone()]].concat(),
+        [outputs.clone(), vec![outputs[0].clone()]].concat(),
+    ));
+    let error = ledger_block::Transition::from(&request, &extended, &output_types, &output_registers).unwrap_err();
+    assert_eq!(error.to_string(), "Output index 2 is out of bounds, as the function declares 1 outputs");
+
+    // Ensure the response rejects the extra output.
+    let error = console::program::Response::new(
+        request.network_id(),
+        request.program_id(),
+        request.function_name(),
+        request.inputs().len(),
+        request.tvk(),
+        request.tcm(),
+        outputs,
+        &output_types,
+        &output_registers,
+    )
+    .unwrap_err();
+    assert_eq!(error.to_string(), "Expected 1 outputs, found 2 outputs and 1 output registers");
+}
+
+#[test]
+fn test_process_output_randomizers() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program token.aleo;
+
+record token:
+    owner as address.private;
+    token_amount as u64.private;
+
+function compute:
+    input r0 as field.private;
+    input r1 as token.record;
+    cast r1.owner r1.token_amount into r2 as token.record;
+    output r2 as token.record;
+    output r0 as field.private;",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the input values.
+    let r0 = Value::<CurrentNetwork>::from_str("3field").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str(&format!(
+        "{{ owner: {caller}.private, token_amount: 100u64.private, _nonce: 0group.public }}"
+    ))
+    .unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "compute", [r0, r1].iter(), rng)
+        .unwrap();
+    let request = authorization.peek_next().unwrap();
+
+    // Compute the output randomizers.
+    let randomizers = process.output_randomizers(request.tvk(), program.id(), "compute").unwrap();
+    assert_eq!(randomizers.len(), 2);
+    assert!(randomizers[0].is_some());
+    assert!(randomizers[1].is_none());
+
+    // Evaluate the request.
+    let response = process.evaluate::<CurrentAleo>(authorization).unwrap();
+
+    // Ensure the record nonces are derived from the randomizers.
+    for (output, randomizer) in response.outputs().iter().zip_eq(&randomizers) {
+        match (output, randomizer) {
+            (Value::Record(record), Some(randomizer)) => {
+                assert_eq!(*record.nonce(), CurrentNetwork::g_scalar_multiply(randomizer));
+            }
+            (Value::Plaintext(..), None) => (),
+            _ => panic!("Unexpected output '{output}'"),
+        }
+    }
+}
+
+#[test]
+fn test_process_execute_events() {
+    // Initialize a new program.
+    let (string, program0) = Program::<CurrentNetwork>::parse(
+        r"
+program zero.aleo;
+
+function double:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.public;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Initialize a second program, which calls the first program.
+    let (string, program1) = Program::<CurrentNetwork>::parse(
+        r"
+import zero.aleo;
+
+program one.aleo;
+
+function compute:
+    input r0 as field.private;
+    call zero.aleo/double r0 into r1;
+    add r1 r0 into r2;
+    output r2 as field.private;
+    output r1 as field.public;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+    process.add_program(&program1).unwrap();
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Prepare the expected events, which are the public outputs of the callee and then of the caller.
+    let expected = vec![
+        Event {
+            program_id: *program0.id(),
+            function_name: Identifier::from_str("double").unwrap(),
+            index: 0,
+            value: Plaintext::from_str("6field").unwrap(),
+        },
+        Event {
+            program_id: *program1.id(),
+            function_name: Identifier::from_str("compute").unwrap(),
+            index: 1,
+            value: Plaintext::from_str("6field").unwrap(),
+        },
+    ];
+
+    // Authorize the function call.
+    let inputs = [Value::<CurrentNetwork>::from_str("3field").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), "compute", inputs.iter(), rng).unwrap();
+
+    // Ensure the events are emitted when evaluating the function.
+    let (response, events) = process.evaluate_with_events::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(response.outputs().len(), 2);
+    assert_eq!(events, expected);
+
+    // Ensure the events are emitted when executing the function.
+    let (_response, trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(trace.events(), expected);
+}
+
+#[test]
+fn test_process_execute_with_timeout() {
+    // Initialize a new program, with a large function.
+    let mut program_string =
+        String::from("program testing.aleo;\n\nfunction compute:\n    input r0 as field.private;\n");
+    for i in 0..100 {
+        program_string.push_str(&format!("    hash.bhp256 r{i} into r{} as field;\n", i + 1));
+    }
+    program_string.push_str("    output r100 as field.private;\n");
+    let program = Program::<CurrentNetwork>::from_str(&program_string).unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let inputs = [Value::<CurrentNetwork>::from_str("3field").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "compute", inputs.iter(), rng).unwrap();
+
+    // Ensure the execution is aborted with a low timeout.
+    let error = process
+        .execute_with_timeout::<CurrentAleo, _>(authorization.replicate(), std::time::Duration::ZERO, rng)
+        .unwrap_err();
+    assert!(error.to_string().contains("Execution timed out"), "{error}");
+
+    // Ensure the execution succeeds with a sufficient timeout.
+    let (response, _trace) = process
+        .execute_with_timeout::<CurrentAleo, _>(authorization.replicate(), std::time::Duration::from_secs(3600), rng)
+        .unwrap();
+    assert_eq!(response.outputs().len(), 1);
+
+    // Ensure the execution succeeds with a timeout that overflows the deadline.
+    let (response, _trace) =
+        process.execute_with_timeout::<CurrentAleo, _>(authorization, std::time::Duration::MAX, rng).unwrap();
+    assert_eq!(response.outputs().len(), 1);
+}
+
+#[test]
+fn test_process_differential_check() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program differential.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    cast self.caller r1 into r2 as token.record;
+    output r1 as u64.public;
+    output r2 as token.record;
+",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let inputs = [Value::<CurrentNetwork>::from_str("5u64").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "mint", inputs.iter(), rng).unwrap();
+
+    // Ensure the native and circuit outputs match.
+    process.differential_check::<CurrentAleo, _>(authorization.replicate(), rng).unwrap();
+
+    // Inject a discrepancy into the native outputs, and ensure it is reported.
+    let response = process.evaluate::<CurrentAleo>(authorization).unwrap();
+    let mut tampered = response.outputs().to_vec();
+    tampered[0] = Value::from_str("11u64").unwrap();
+    let error = Process::ensure_outputs_match(&tampered, response.outputs()).unwrap_err();
+    assert!(error.to_string().contains("output 0: evaluate '11u64' != execute '10u64'"), "{error}");
+
+    // Ensure a mismatched number of outputs is reported.
+    let error = Process::ensure_outputs_match(&tampered[..1], response.outputs()).unwrap_err();
+    assert!(error.to_string().contains("evaluate returned 1 outputs, execute returned 2 outputs"), "{error}");
+}
+
+#[test]
+fn test_process_evaluate_full() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program evaluate_full.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    cast self.caller r1 into r2 as token.record;
+    output r1 as u64.public;
+    output r2 as token.record;
+",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let inputs = [Value::<CurrentNetwork>::from_str("5u64").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "mint", inputs.iter(), rng).unwrap();
+
+    // Evaluate the function.
+    let result = process.evaluate_full::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(result.response().outputs()[0], Value::from_str("10u64").unwrap());
+    assert_eq!(result.leaves().len(), 3);
+
+    // Ensure the trace root is stable for the same inputs.
+    let expected = process.evaluate_full::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(result.trace_root(), expected.trace_root());
+    assert_eq!(result.leaves(), expected.leaves());
+
+    // Ensure the trace root matches the root of the executed transition.
+    let (_, trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let transition = &trace.transitions()[0];
+    assert_eq!(result.trace_root(), &transition.to_root().unwrap());
+    let leaves = transition
+        .input_ids()
+        .map(|id| transition.to_leaf(id, true).unwrap())
+        .chain(transition.output_ids().map(|id| transition.to_leaf(id, false).unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(result.leaves(), leaves);
+
+    // Ensure each leaf is in the transition tree, at its index.
+    for (index, leaf) in result.leaves().iter().enumerate() {
+        assert_eq!(leaf.index() as usize, index);
+        let path = transition.to_path(leaf).unwrap();
+        assert!(CurrentNetwork::verify_merkle_path_bhp(&path, result.trace_root(), &leaf.to_bits_le()));
+    }
+}
+
+#[test]
+fn test_process_execute_large_record_output() {
+    // Initialize a new program, with a record whose ciphertext spans several BHP1024 input blocks.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program large_output.aleo;
+
+record large:
+    owner as address.private;
+    a0 as u128.private;
+    a1 as u128.private;
+    a2 as u128.private;
+    a3 as u128.private;
+    a4 as u128.private;
+    a5 as u128.private;
+    a6 as u128.private;
+    a7 as u128.private;
+    a8 as u128.private;
+    a9 as u128.private;
+    a10 as u128.private;
+    a11 as u128.private;
+    a12 as u128.private;
+    a13 as u128.private;
+    a14 as u128.private;
+    a15 as u128.private;
+
+function mint:
+    input r0 as u128.private;
+    cast self.caller r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 into r1 as large.record;
+    output r1 as large.record;
+",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let inputs = [Value::<CurrentNetwork>::from_str("340282366920938463463374607431768211455u128").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "mint", inputs.iter(), rng).unwrap();
+
+    // Execute the function.
+    let (_, trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let transition = &trace.transitions()[0];
+
+    // Ensure the record ciphertext exceeds a single BHP1024 input block, and its checksum is consistent.
+    let (_, record) = transition.outputs()[0].record().unwrap();
+    assert!(record.to_bits_le().len() > 4 * 1024);
+    let function_id =
+        compute_function_id(&U16::new(CurrentNetwork::ID), program.id(), transition.function_name()).unwrap();
+    assert!(transition.verify_outputs(function_id));
+}
+
+#[test]
+fn test_process_benchmark_execute() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program benchmark.aleo;
+
+function compute:
+    input r0 as field.private;
+    mul r0 r0 into r1;
+    output r1 as field.private;
+",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let inputs = [Value::<CurrentNetwork>::from_str("3field").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "compute", inputs.iter(), rng).unwrap();
+
+    // Benchmark the execution.
+    let benchmark = process.benchmark_execute::<CurrentAleo, _>(&authorization, 2, rng).unwrap();
+    assert_eq!(benchmark.samples().len(), 2);
+    assert!(benchmark.min() <= benchmark.median() && benchmark.median() <= benchmark.max());
+
+    // Ensure zero iterations are rejected.
+    assert!(process.benchmark_execute::<CurrentAleo, _>(&authorization, 0, rng).is_err());
+}
+
+#[test]
+fn test_process_public_witness() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program public_witness.aleo;
+
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    mul r0 r1 into r3;
+    output r2 as field.public;
+    output r3 as field.private;
+",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let inputs = [Value::<CurrentNetwork>::from_str("3field").unwrap(), Value::from_str("5field").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "compute", inputs.iter(), rng).unwrap();
+
+    // Compute the public witness.
+    let witness = process.public_witness::<CurrentAleo, _>(authorization.replicate(), rng).unwrap();
+
+    // Execute the function.
+    let (response, trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let transition = &trace.transitions()[0];
+
+    // Ensure the public witness starts with the constant one and commits to the transition.
+    assert_eq!(witness[0], Field::one());
+    assert_eq!(witness[3], *transition.tcm());
+    assert_eq!(witness[4], *transition.scm());
+    // Ensure the public witness ends with the output IDs from the execution.
+    let output_ids = &witness[witness.len() - response.output_ids().len()..];
+    assert_eq!(output_ids, transition.output_ids().copied().collect::<Vec<_>>());
+}
+
+#[test]
+fn test_process_validate_outputs() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program validate_outputs.aleo;
+
+function compute:
+    input r0 as field.public;
+    add r0 r0 into r1;
+    output r1 as field.public;
+    output r0 as field.private;
+",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Execute the function.
+    let inputs = [Value::<CurrentNetwork>::from_str("3field").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "compute", inputs.iter(), rng).unwrap();
+    let (_, trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let outputs = trace.transitions()[0].outputs().to_vec();
+
+    // Ensure the outputs match the function signature.
+    process.validate_outputs(program.id(), "compute", &outputs).unwrap();
+
+    // Ensure a public output that became a private output is rejected.
+    let mut mismatched = outputs.clone();
+    mismatched[0] = Output::Private(*outputs[0].id(), None);
+    let error = process.validate_outputs(program.id(), "compute", &mismatched).unwrap_err();
+    assert!(error.to_string().contains("Output 0 of 'validate_outputs.aleo/compute' does not match"), "{error}");
+
+    // Ensure a missing output is rejected.
+    assert!(process.validate_outputs(program.id(), "compute", &outputs[..1]).is_err());
+}
+
+#[test]
+fn test_process_validate_constant_outputs() {
+    // Initialize a program, which is called by the next program.
+    let callee = Program::<CurrentNetwork>::from_str(
+        r"
+program constant_callee.aleo;
+
+function caller:
+    input r0 as field.constant;
+    output self.caller as address.public;
+",
+    )
+    .unwrap();
+
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+import constant_callee.aleo;
+
+program constant_outputs.aleo;
+
+function literal:
+    input r0 as field.constant;
+    add r0 1field into r1;
+    mul 2field 3field into r2;
+    output r1 as field.constant;
+    output r2 as field.constant;
+
+function leaky:
+    input r0 as field.private;
+    add r0 1field into r1;
+    mul r1 2field into r2;
+    output r2 as field.constant;
+
+function external:
+    input r0 as field.constant;
+    call constant_callee.aleo/caller r0 into r1;
+    output r1 as address.constant;
+",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&callee);
+    process.add_program(&program).unwrap();
+
+    // Ensure constant outputs computed from constants are accepted.
+    process.validate_constant_outputs(program.id(), "literal").unwrap();
+
+    // Ensure a constant output computed from a private input is rejected.
+    let error = process.validate_constant_outputs(program.id(), "leaky").unwrap_err();
+    assert_eq!(error.to_string(), "Constant output 0 of 'constant_outputs.aleo/leaky' depends on a non-constant value");
+
+    // Ensure a constant output of an external call is rejected, even if the call only takes constant operands.
+    let error = process.validate_constant_outputs(program.id(), "external").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Constant output 0 of 'constant_outputs.aleo/external' depends on a non-constant value"
+    );
+}
+
+#[test]
+fn test_trace_prove_output() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program prove_output.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    cast self.caller r1 into r2 as token.record;
+    output r1 as u64.public;
+    output r2 as token.record;
+",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Execute the function.
+    let inputs = [Value::<CurrentNetwork>::from_str("5u64").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "mint", inputs.iter(), rng).unwrap();
+    let (_, trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let transition = &trace.transitions()[0];
+    let root = transition.to_root().unwrap();
+
+    // Ensure the proof for the record output verifies.
+    let (leaf, path) = trace.prove_output(0, 1).unwrap();
+    assert!(Trace::verify_output_proof(&root, &transition.outputs()[1], &leaf, &path));
+
+    // Ensure the proof does not verify for another output, or against another root.
+    assert!(!Trace::verify_output_proof(&root, &transition.outputs()[0], &leaf, &path));
+    assert!(!Trace::verify_output_proof(&Field::one(), &transition.outputs()[1], &leaf, &path));
+
+    // Ensure out-of-bounds indices are rejected.
+    assert!(trace.prove_output(0, 2).is_err());
+    assert!(trace.prove_output(1, 0).is_err());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+fn test_log_circuit_without_color() {
+    // Ensure the log has no ANSI escapes when color is disabled.
+    let log = Stack::<CurrentNetwork>::format_circuit_log::<CurrentAleo, _>("Request", false);
+    assert!(log.starts_with(&format!("✅ {:20} (Constant: ", "Request")), "{log}");
+    assert!(!log.contains('\x1b'), "{log}");
+
+    // Ensure the scope is padded to the same width when color is enabled.
+    colored::control::set_override(true);
+    let log = Stack::<CurrentNetwork>::format_circuit_log::<CurrentAleo, _>("Request", true);
+    colored::control::unset_override();
+    assert!(log.contains(&format!("{:20}\x1b[0m (Constant: ", "Request")), "{log}");
+
+    // Ensure the setting is applied to the logs.
+    Process::<CurrentNetwork>::set_log_color(false);
+    assert!(!crate::execute::LOG_COLOR.load(std::sync::atomic::Ordering::Relaxed));
+    Process::<CurrentNetwork>::set_log_color(true);
+}
+
+#[test]
+fn test_process_execute_closure_metrics() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program closure_metrics.aleo;
+
+closure execute:
+    input r0 as field;
+    input r1 as field;
+    mul r0 r1 into r2;
+    add r2 r0 into r3;
+    output r2 as field;
+    output r3 as field;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.private;
+    call execute r0 r1 into r2 r3;
+    output r3 as field.private;
+",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Execute the function.
+    let inputs = [Value::<CurrentNetwork>::from_str("3field").unwrap(), Value::from_str("5field").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "compute", inputs.iter(), rng).unwrap();
+    let (_, trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+
+    // Ensure the closure call has its own metrics entry.
+    assert_eq!(trace.call_metrics().len(), 1);
+    assert_eq!(trace.closure_metrics().len(), 1);
+    let metrics = &trace.closure_metrics()[0];
+    assert_eq!(metrics.program_id, *program.id());
+    assert_eq!(metrics.function_name, Identifier::from_str("execute").unwrap());
+    assert_eq!(metrics.num_instructions, 2);
+    assert!(metrics.num_function_constraints > 0);
+    // Ensure the closure constraints are attributed within the calling function.
+    assert!(metrics.num_function_constraints <= trace.call_metrics()[0].num_function_constraints);
+}
+
+#[test]
+fn test_process_cast_u64_to_u8() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program integer_casts.aleo;
+
+function truncate:
+    input r0 as u64.private;
+    cast.lossy r0 into r1 as u8;
+    output r1 as u8.private;
+
+function checked:
+    input r0 as u64.private;
+    cast r0 into r1 as u8;
+    output r1 as u8.private;
+",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    for (input, expected) in
+        [("255u64", "255u8"), ("256u64", "0u8"), ("300u64", "44u8"), ("18446744073709551615u64", "255u8")]
+    {
+        let inputs = [Value::<CurrentNetwork>::from_str(input).unwrap()];
+
+        // Ensure the lossy cast truncates to the low-order bits, consistently in `evaluate` and `execute`.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "truncate", inputs.iter(), rng)
+            .unwrap();
+        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        assert_eq!(response.outputs(), [Value::from_str(expected).unwrap()]);
+        process.differential_check::<CurrentAleo, _>(authorization, rng).unwrap();
+
+        // Ensure the checked cast rejects values above 255, consistently in `evaluate` and `execute`.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "checked", inputs.iter(), rng)
+            .unwrap();
+        match input == "255u64" {
+            true => process.differential_check::<CurrentAleo, _>(authorization, rng).unwrap(),
+            false => {
+                assert!(process.evaluate::<CurrentAleo>(authorization.replicate()).is_err());
+                assert!(process.execute::<CurrentAleo, _>(authorization, rng).is_err());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_process_evaluate_releases_registers() {
+    // Initialize a new program, which creates and discards a large intermediate value.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program releases.aleo;
+
+struct large:
+    a as u64;
+    b as u64;
+    c as u64;
+    d as u64;
+    e as u64;
+    f as u64;
+    g as u64;
+    h as u64;
+
+function discard:
+    input r0 as u64.private;
+    add r0 1u64 into r1;
+    cast r0 r1 r0 r1 r0 r1 r0 r1 into r2 as large;
+    add r2.a r2.b into r3;
+    mul r3 2u64 into r4;
+    add r4 1u64 into r5;
+    add r5 1u64 into r6;
+    output r6 as u64.private;
+",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let inputs = [Value::<CurrentNetwork>::from_str("5u64").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "discard", inputs.iter(), rng).unwrap();
+
+    // Evaluate the function.
+    crate::PEAK_LIVE_REGISTERS.with(|peak| peak.set(0));
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(response.outputs(), [Value::from_str("24u64").unwrap()]);
+
+    // Ensure the registers are released after their last use, so at most 3 registers are live at once.
+    assert_eq!(crate::PEAK_LIVE_REGISTERS.with(|peak| peak.get()), 3);
+
+    // Ensure the execution matches the evaluation.
+    process.differential_check::<CurrentAleo, _>(authorization, rng).unwrap();
+}
+
+#[test]
+fn test_process_prove() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "compute", ["5u32", "10u32"].into_iter(), rng)
+        .unwrap();
+
+    // Prove the execution.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    let (response, execution) = process.prove::<CurrentAleo, _>(authorization, Query::from(block_store), rng).unwrap();
+    assert_eq!(response.outputs(), [Value::from_str("15u32").unwrap()]);
+    assert!(execution.proof().is_some());
+
+    // Ensure the proof verifies with the verifying key of the function.
+    process.verify_execution(&execution).unwrap();
+}
+
+#[test]
+fn test_process_execute_at_snapshot() {
+    // Initialize a new program.
+    let program0 = Program::<CurrentNetwork>::from_str(
+        r"
+program snapshot.aleo;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.public;
+",
+    )
+    .unwrap();
+    // Initialize a second program, which imports the first program.
+    let program1 = Program::<CurrentNetwork>::from_str(
+        r"
+import snapshot.aleo;
+
+program snapshot_caller.aleo;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.private;
+    call snapshot.aleo/compute r0 r1 into r2;
+    mul r2 r1 into r3;
+    output r3 as field.public;
+",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process, and take two snapshots of the first program.
+    let mut process = crate::test_helpers::sample_process(&program0);
+    let snapshot_a = process.snapshot(program0.id()).unwrap();
+    let snapshot_b = process.clone().snapshot(program0.id()).unwrap();
+    assert!(snapshot_a == snapshot_b);
+    assert_eq!(snapshot_a.program_ids().collect::<Vec<_>>(), [program0.id()]);
+
+    // Authorize the function call.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let inputs = [Value::<CurrentNetwork>::from_str("2field").unwrap(), Value::from_str("3field").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program0.id(), "compute", inputs.iter(), rng).unwrap();
+
+    // Ensure executing against either snapshot yields identical outputs.
+    let (response_a, _) = process.execute_at::<CurrentAleo, _>(authorization.replicate(), &snapshot_a, rng).unwrap();
+    let (response_b, _) = process.execute_at::<CurrentAleo, _>(authorization.replicate(), &snapshot_b, rng).unwrap();
+    assert_eq!(response_a.outputs(), [Value::from_str("5field").unwrap()]);
+    assert_eq!(response_a.outputs(), response_b.outputs());
+
+    // Add the second program to the process, and ensure it is not in the earlier snapshot.
+    process.add_program(&program1).unwrap();
+    assert!(!snapshot_a.contains_program(program1.id()));
+    let authorization1 =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), "compute", inputs.iter(), rng).unwrap();
+    let error = process.execute_at::<CurrentAleo, _>(authorization1.replicate(), &snapshot_a, rng).unwrap_err();
+    assert!(error.to_string().contains("does not exist in the snapshot"), "{error}");
+
+    // Ensure executing against the earlier snapshot still yields the original outputs.
+    let (response, _) = process.execute_at::<CurrentAleo, _>(authorization, &snapshot_a, rng).unwrap();
+    assert_eq!(response.outputs(), response_a.outputs());
+
+    // Ensure a snapshot of the second program includes the program it imports.
+    let snapshot = process.snapshot(program1.id()).unwrap();
+    assert_eq!(snapshot.program_ids().collect::<Vec<_>>(), [program1.id(), program0.id()]);
+    let (response, _) = process.execute_at::<CurrentAleo, _>(authorization1, &snapshot, rng).unwrap();
+    assert_eq!(response.outputs(), [Value::from_str("15field").unwrap()]);
+}
+
+#[test]
+fn test_process_function_profile() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    token_amount as u64.private;
+
+closure execute:
+    input r0 as field;
+    input r1 as field;
+    add r0 r1 into r2;
+    add r0 r2 into r3;
+    add r2 r3 into r4;
+    output r4 as field;
+    output r3 as field;
+    output r2 as field;
+
+closure check_not_equal:
+    input r0 as field;
+    input r1 as field;
+    assert.neq r0 r1;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.public;
+    input r2 as token.record;
+    cast r2.owner r2.token_amount into r3 as token.record;
+    call check_not_equal r0 r1;
+    call execute r0 r1 into r4 r5 r6;
+    output r3 as token.record;
+    output r4 as field.private;
+    output r5 as field.private;
+    output r6 as field.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Ensure the function profile counts the instructions of the function only.
+    let profile = process.function_profile(program.id(), "compute").unwrap();
+    assert_eq!(profile.count("call"), 2);
+    assert_eq!(profile.count("cast"), 1);
+    assert_eq!(profile.count("add"), 0);
+    assert_eq!(profile.num_instructions(), 3);
+    assert_eq!(profile.iter().len(), 2);
+
+    // Ensure the closure profile counts the instructions of the closure.
+    let profile = process.function_profile(program.id(), "execute").unwrap();
+    assert_eq!(profile.count("add"), 3);
+    assert_eq!(profile.count("call"), 0);
+    assert_eq!(profile.num_instructions(), 3);
+
+    // Ensure an unknown function is rejected.
+    assert!(process.function_profile(program.id(), "unknown").is_err());
+}
+
+#[test]
+fn test_process_validate_program() {
+    // Initialize a program that calls an undefined closure, and outputs an undefined record.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program dangling.aleo;
+
+closure helper:
+    input r0 as field;
+    add r0 r0 into r1;
+    output r1 as field;
+
+function compute:
+    input r0 as field.private;
+    call missing r0 into r1;
+    output r1 as field.private;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    cast r0 r1 into r2 as token.record;
+    output r2 as token.record;
+",
+    )
+    .unwrap();
+
+    // Initialize the process.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+
+    // Ensure every undefined reference is reported.
+    let error = process.validate_program(&program).unwrap_err().to_string();
+    assert!(error.contains("Call target 'missing' is not defined"), "{error}");
+    assert!(error.contains("Record 'token' is not defined"), "{error}");
+    assert!(!error.contains("helper"), "{error}");
+
+    // Ensure the program is rejected when it is added to the process.
+    let error = process.add_program(&program).unwrap_err();
+    assert!(error.to_string().contains("undefined references"), "{error}");
+    assert!(!process.contains_program(program.id()));
+
+    // Ensure a program with defined references is accepted.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program defined.aleo;
+
+closure helper:
+    input r0 as field;
+    add r0 r0 into r1;
+    output r1 as field;
+
+function compute:
+    input r0 as field.private;
+    call helper r0 into r1;
+    output r1 as field.private;
+",
+    )
+    .unwrap();
+    process.validate_program(&program).unwrap();
+    process.add_program(&program).unwrap();
+}
+
+#[test]
+fn test_process_function_abi() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    token_amount as u64.private;
+
+closure execute:
+    input r0 as field;
+    input r1 as field;
+    add r0 r1 into r2;
+    add r0 r2 into r3;
+    add r2 r3 into r4;
+    output r4 as field;
+    output r3 as field;
+    output r2 as field;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.public;
+    input r2 as token.record;
+    cast r2.owner r2.token_amount into r3 as token.record;
+    call execute r0 r1 into r4 r5 r6;
+    output r3 as token.record;
+    output r4 as field.private;
+    output r5 as field.private;
+    output r6 as field.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Retrieve the function ABI.
+    let abi = process.function_abi(program.id(), "compute").unwrap();
+    assert_eq!(abi.program_id(), program.id());
+    assert_eq!(abi.function_name().to_string(), "compute");
+
+    // Ensure the inputs are described.
+    let inputs = abi.inputs().iter().map(|i| (i.name(), i.type_name(), i.visibility())).collect::<Vec<_>>();
+    assert_eq!(inputs, [
+        ("r0", "field".to_string(), "private"),
+        ("r1", "field".to_string(), "public"),
+        ("r2", "token".to_string(), "record")
+    ]);
+    // Ensure the outputs are described.
+    let outputs = abi.outputs().iter().map(|o| (o.name(), o.type_name(), o.visibility())).collect::<Vec<_>>();
+    assert_eq!(outputs, [
+        ("r3", "token".to_string(), "record"),
+        ("r4", "field".to_string(), "private"),
+        ("r5", "field".to_string(), "private"),
+        ("r6", "field".to_string(), "private")
+    ]);
+
+    // Ensure the ABI serializes to JSON.
+    let json = serde_json::to_value(&abi).unwrap();
+    assert_eq!(json["program"], "token.aleo");
+    assert_eq!(json["function"], "compute");
+    assert_eq!(json["inputs"].as_array().unwrap().len(), 3);
+    assert_eq!(json["outputs"].as_array().unwrap().len(), 4);
+    assert_eq!(json["inputs"][2], serde_json::json!({ "name": "r2", "type": "token", "visibility": "record" }));
+    assert_eq!(abi.to_string(), json.to_string());
+
+    // Ensure a closure or an unknown function is rejected.
+    assert!(process.function_abi(program.id(), "execute").is_err());
+    assert!(process.function_abi(program.id(), "unknown").is_err());
+}
+
+#[test]
+fn test_process_recover_tvk() {
+    // Initialize a new program, with a private output.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program recover.aleo;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;
+",
+    )
+    .unwrap();
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+
+    // Authorize and execute the function call.
+    let inputs = [Value::<CurrentNetwork>::from_str("2field").unwrap(), Value::from_str("3field").unwrap()];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+    let expected_tvk = *authorization.peek_next().unwrap().tvk();
+    let (_, trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let transition = &trace.transitions()[0];
+
+    // Ensure the tvk is recovered from the view key.
+    let tvk = process.recover_tvk(&caller_view_key, transition).unwrap();
+    assert_eq!(tvk, expected_tvk);
+
+    // Ensure the private output is decrypted with the recovered tvk.
+    let Output::Private(_, Some(ciphertext)) = &transition.outputs()[0] else { panic!("Expected a private output") };
+    let function_id = compute_function_id(&U16::new(CurrentNetwork::ID), program.id(), &function_name).unwrap();
+    let index = Field::from_u16(inputs.len() as u16);
+    let output_view_key = CurrentNetwork::hash_psd4(&[function_id, tvk, index]).unwrap();
+    assert_eq!(ciphertext.decrypt_symmetric(output_view_key).unwrap(), Plaintext::from_str("5field").unwrap());
+
+    // Ensure a different view key is rejected.
+    let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    assert!(process.recover_tvk(&other_view_key, transition).is_err());
+}
+
+#[test]
+fn test_process_execute_public_owner_record() {
+    // Initialize a new program, with a record that has a public owner.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program public_owner.aleo;
+
+record token:
+    owner as address.public;
+    amount as u64.private;
+
+function transfer:
+    input r0 as token.record;
+    input r1 as address.private;
+    cast r1 r0.amount into r2 as token.record;
+    output r2 as token.record;
+",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize the caller and the recipient.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+    let recipient_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let recipient_view_key = ViewKey::try_from(&recipient_private_key).unwrap();
+    let recipient = Address::try_from(&recipient_private_key).unwrap();
+
+    // Declare the inputs.
+    let record = format!("{{ owner: {caller}.public, amount: 100u64.private, _nonce: 0group.public }}");
+    let inputs =
+        [Value::<CurrentNetwork>::from_str(&record).unwrap(), Value::from_str(&recipient.to_string()).unwrap()];
+
+    // Authorize the function call.
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "transfer", inputs.iter(), rng).unwrap();
+    let request = authorization.peek_next().unwrap();
+
+    // Compute the expected output record, which keeps the owner public.
+    let randomizer = CurrentNetwork::hash_to_scalar_psd2(&[*request.tvk(), Field::from_u64(2)]).unwrap();
+    let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+    let expected =
+        Value::from_str(&format!("{{ owner: {recipient}.public, amount: 100u64.private, _nonce: {nonce}.public }}"))
+            .unwrap();
+
+    // Ensure `evaluate` and `execute` produce the expected output record.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(response.outputs(), [expected.clone()]);
+    let (response, trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(response.outputs(), [expected.clone()]);
+
+    // Ensure the record ciphertext keeps the owner public, and decrypts to the expected record.
+    assert_eq!(trace.transitions().len(), 1);
+    let (_, ciphertext) = trace.transitions()[0].records().next().unwrap();
+    assert!(ciphertext.owner().is_public());
+    assert!(ciphertext.is_owner(&recipient_view_key));
+    let Value::Record(expected) = expected else { unreachable!() };
+    assert_eq!(ciphertext.decrypt(&recipient_view_key).unwrap(), expected);
+}
+
+#[test]
+fn test_process_external_record_program_id() {
+    // Initialize a new program.
+    let program0 = Program::<CurrentNetwork>::from_str(
+        r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as u64.private;
+    cast self.caller r0 into r1 as token.record;
+    output r1 as token.record;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+    // Initialize another program.
+    let program1 = Program::<CurrentNetwork>::from_str(
+        r"
+import token.aleo;
+
+program wallet.aleo;
+
+function mint:
+    input r0 as u64.private;
+    call token.aleo/mint r0 into r1;
+    output r1 as token.aleo/token.record;",
+    )
+    .unwrap();
+    process.add_program(&program1).unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize and execute the function call.
+    let inputs = [Value::<CurrentNetwork>::from_str("5u64").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), "mint", inputs.iter(), rng).unwrap();
+    let (_, trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let transitions = trace.transitions();
+    assert_eq!(transitions.len(), 2);
+
+    // Ensure the external record output of 'wallet.aleo/mint' originates from 'token.aleo'.
+    let transition = &transitions[1];
+    assert_eq!(transition.program_id(), program1.id());
+    assert_eq!(process.external_record_program_id(transition, 0).unwrap(), *program0.id());
+    // Ensure an output index out of bounds is rejected.
+    assert!(process.external_record_program_id(transition, 1).is_err());
+    // Ensure the record output of 'token.aleo/mint' is rejected, as it is not an external record.
+    assert!(process.external_record_program_id(&transitions[0], 0).is_err());
+}
+
+#[test]
+fn test_process_verify_execution_rejects_zero_external_record() {
+    // Initialize a new program.
+    let program0 = Program::<CurrentNetwork>::from_str(
+        r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as u64.private;
+    cast self.caller r0 into r1 as token.record;
+    output r1 as token.record;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+    // Initialize another program.
+    let program1 = Program::<CurrentNetwork>::from_str(
+        r"
+import token.aleo;
+
+program wallet.aleo;
+
+function mint:
+    input r0 as u64.private;
+    call token.aleo/mint r0 into r1;
+    output r1 as token.aleo/token.record;",
+    )
+    .unwrap();
+    process.add_program(&program1).unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize and execute the function call.
+    let inputs = [Value::<CurrentNetwork>::from_str("5u64").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), "mint", inputs.iter(), rng).unwrap();
+    let (_, trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let transitions = trace.transitions().to_vec();
+
+    // Ensure the outputs of the (unproven) execution are accepted, so it fails at a later check.
+    let execution = Execution::from(transitions.clone().into_iter(), Default::default(), None).unwrap();
+    let error = process.verify_execution(&execution).unwrap_err();
+    assert_ne!(error.to_string(), "Failed to verify a transition output");
+
+    // Replace the external record output of 'wallet.aleo/mint' with a zero ID.
+    let transition = &transitions[1];
+    assert!(matches!(transition.outputs()[0], Output::ExternalRecord(..)));
+    let tampered = Transition::new(
+        *transition.program_id(),
+        *transition.function_name(),
+        transition.inputs().to_vec(),
+        vec![Output::ExternalRecord(Field::zero())],
+        *transition.tpk(),
+        *transition.tcm(),
+        *transition.scm(),
+    )
+    .unwrap();
+
+    // Ensure the execution is rejected for the zero external record ID.
+    let execution = Execution::from([transitions[0].clone(), tampered].into_iter(), Default::default(), None).unwrap();
+    let error = process.verify_execution(&execution).unwrap_err();
+    assert_eq!(error.to_string(), "Failed to verify a transition output");
+}
+
+#[test]
+fn test_process_execute_by_selector() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program example_selector.aleo;
+
+closure execute:
+    input r0 as field;
+    input r1 as field;
+    add r0 r1 into r2;
+    add r0 r2 into r3;
+    add r2 r3 into r4;
+    output r4 as field;
+    output r3 as field;
+    output r2 as field;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.public;
+    call execute r0 r1 into r2 r3 r4;
+    output r2 as field.private;
+    output r3 as field.private;
+    output r4 as field.private;
+
+function double:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Compute the selectors, and ensure they resolve back to their functions.
+    let selector = process.function_selector(program.id(), "compute").unwrap();
+    assert_eq!(process.function_selector(program.id(), "compute").unwrap(), selector);
+    assert_eq!(process.function_by_selector(program.id(), selector).unwrap().to_string(), "compute");
+    let double_selector = process.function_selector(program.id(), "double").unwrap();
+    assert_ne!(selector, double_selector);
+    assert_eq!(process.function_by_selector(program.id(), double_selector).unwrap().to_string(), "double");
+
+    // Ensure a closure has no selector, and an unknown selector is rejected.
+    assert!(process.function_selector(program.id(), "execute").is_err());
+    let unknown_selector = (0..).find(|s| *s != selector && *s != double_selector).unwrap();
+    assert!(process.function_by_selector(program.id(), unknown_selector).is_err());
+
+    // Ensure the function is executed by its selector.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let inputs = [Value::<CurrentNetwork>::from_str("3field").unwrap(), Value::from_str("5field").unwrap()];
+    let (response, _trace) = process
+        .execute_by_selector::<CurrentAleo, _>(&caller_private_key, program.id(), selector, inputs.iter(), rng)
+        .unwrap();
+    let expected = ["19field", "11field", "8field"].map(|output| Value::from_str(output).unwrap());
+    assert_eq!(response.outputs(), expected);
+}
@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::Network,
+    program::{Identifier, Plaintext, ProgramID, Value, ValueType},
+};
+use ledger_block::{Output, Transition};
+
+/// An event emitted by a program function, which is a public output of the function.
+/// Note: As the value is a public output, it is bound to the output ID of the transition,
+/// and is therefore proven along with the transition.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Event<N: Network> {
+    pub program_id: ProgramID<N>,
+    pub function_name: Identifier<N>,
+    /// The index of the output in the function.
+    pub index: usize,
+    pub value: Plaintext<N>,
+}
+
+impl<N: Network> Event<N> {
+    /// Returns the events for the given outputs of a function, which are its public outputs, in order.
+    pub(crate) fn from_outputs(
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        outputs: &[Value<N>],
+        output_types: &[ValueType<N>],
+    ) -> Vec<Self> {
+        outputs
+            .iter()
+            .zip(output_types)
+            .enumerate()
+            .filter_map(|(index, output)| match output {
+                (Value::Plaintext(value), ValueType::Public(_)) => {
+                    Some(Self { program_id: *program_id, function_name: *function_name, index, value: value.clone() })
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the events for the given transition, which are its public outputs, in order.
+    pub(crate) fn from_transition(transition: &Transition<N>) -> Vec<Self> {
+        transition
+            .outputs()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, output)| match output {
+                Output::Public(_, Some(value)) => Some(Self {
+                    program_id: *transition.program_id(),
+                    function_name: *transition.function_name(),
+                    index,
+                    value: value.clone(),
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+}
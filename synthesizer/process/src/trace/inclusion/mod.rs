@@ -236,3 +236,204 @@ impl<N: Network> InclusionAssignment<N> {
         Ok(A::eject_assignment_and_reset())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+    use console::{
+        account::{Address, PrivateKey},
+        network::MainnetV0,
+        program::{state_path::test_helpers::sample_global_state_path, Identifier, Value},
+    };
+    use synthesizer_program::Program;
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    /// A query that returns a fixed state path for every commitment.
+    #[derive(Clone)]
+    struct MockQuery {
+        state_path: StatePath<CurrentNetwork>,
+    }
+
+    #[cfg_attr(feature = "async", async_trait::async_trait(?Send))]
+    impl QueryTrait<CurrentNetwork> for MockQuery {
+        fn current_state_root(&self) -> Result<<CurrentNetwork as Network>::StateRoot> {
+            Ok(self.state_path.global_state_root())
+        }
+
+        #[cfg(feature = "async")]
+        async fn current_state_root_async(&self) -> Result<<CurrentNetwork as Network>::StateRoot> {
+            self.current_state_root()
+        }
+
+        fn get_state_path_for_commitment(
+            &self,
+            _commitment: &Field<CurrentNetwork>,
+        ) -> Result<StatePath<CurrentNetwork>> {
+            Ok(self.state_path.clone())
+        }
+
+        #[cfg(feature = "async")]
+        async fn get_state_path_for_commitment_async(
+            &self,
+            commitment: &Field<CurrentNetwork>,
+        ) -> Result<StatePath<CurrentNetwork>> {
+            self.get_state_path_for_commitment(commitment)
+        }
+    }
+
+    #[test]
+    fn test_prepare_with_mock_inclusion() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function burn:
+    input r0 as token.record;
+    output r0.amount as u64.public;",
+        )
+        .unwrap();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+        process.add_program(&program).unwrap();
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Authorize the function call.
+        let record = Value::<CurrentNetwork>::from_str(&format!(
+            "{{ owner: {caller}.private, amount: 100u64.private, _nonce: 0group.public }}"
+        ))
+        .unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(
+                &caller_private_key,
+                program.id(),
+                Identifier::from_str("burn").unwrap(),
+                [record].iter(),
+                rng,
+            )
+            .unwrap();
+        let request = authorization.peek_next().unwrap();
+        let transition = authorization.transitions().values().next().unwrap().clone();
+
+        // Retrieve the commitment of the input record.
+        let Some(InputID::Record(commitment, _, serial_number, ..)) = request.input_ids().first() else {
+            panic!("Expected a record input");
+        };
+
+        // Register the transition for inclusion.
+        let mut inclusion = Inclusion::<CurrentNetwork>::new();
+        inclusion.insert_transition(request.input_ids(), &transition).unwrap();
+
+        // Prepare the inclusion assignments, using a mock state path for the record.
+        let state_path = sample_global_state_path::<CurrentNetwork>(Some(*commitment), rng).unwrap();
+        let query = MockQuery { state_path: state_path.clone() };
+        let (assignments, global_state_root) = inclusion.prepare(&[transition.clone()], query).unwrap();
+
+        // Ensure the record input received an assignment for the mock state path.
+        assert_eq!(global_state_root, state_path.global_state_root());
+        assert_eq!(assignments.len(), 1);
+        assert_eq!(assignments[0].state_path, state_path);
+        assert_eq!(&assignments[0].commitment, commitment);
+        assert_eq!(&assignments[0].serial_number, serial_number);
+        assert!(assignments[0].is_global);
+
+        // Ensure the assignment synthesizes into an inclusion circuit.
+        let assignment = assignments[0].to_circuit_assignment::<CurrentAleo>().unwrap();
+        assert!(assignment.num_constraints() > 0);
+
+        // Ensure the verifier inputs are derived for the same record.
+        let verifier_inputs = Inclusion::prepare_verifier_inputs(global_state_root, [transition].iter()).unwrap();
+        assert_eq!(verifier_inputs.len(), 1);
+    }
+
+    #[test]
+    fn test_call_metrics_with_inclusion() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function burn:
+    input r0 as token.record;
+    output r0.amount as u64.public;
+
+function noop:
+    input r0 as u64.public;
+    output r0 as u64.public;",
+        )
+        .unwrap();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+        process.add_program(&program).unwrap();
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Construct a trace for the given function call, without executing the function.
+        let sample_trace = |function_name: &str, input: Value<CurrentNetwork>, rng: &mut TestRng| {
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [input].iter(), rng)
+                .unwrap();
+            let request = authorization.peek_next().unwrap();
+            let transition = authorization.transitions().values().next().unwrap().clone();
+            let mut trace = crate::Trace::<CurrentNetwork>::new();
+            trace.inclusion_tasks.insert_transition(request.input_ids(), &transition).unwrap();
+            trace.transitions.push(transition);
+            (request, trace)
+        };
+
+        // Construct a trace for a function with a record input.
+        let record = Value::<CurrentNetwork>::from_str(&format!(
+            "{{ owner: {caller}.private, amount: 100u64.private, _nonce: 0group.public }}"
+        ))
+        .unwrap();
+        let (request, mut trace) = sample_trace("burn", record, rng);
+
+        // Ensure the inclusion metrics are unavailable before the trace is prepared.
+        assert!(trace.call_metrics_with_inclusion::<CurrentAleo>().is_err());
+
+        // Prepare the trace, using a mock state path for the record.
+        let Some(InputID::Record(commitment, ..)) = request.input_ids().first() else {
+            panic!("Expected a record input");
+        };
+        let state_path = sample_global_state_path::<CurrentNetwork>(Some(*commitment), rng).unwrap();
+        trace.prepare(MockQuery { state_path: state_path.clone() }).unwrap();
+
+        // Ensure the inclusion metrics are appended after the call metrics.
+        let call_metrics = trace.call_metrics_with_inclusion::<CurrentAleo>().unwrap();
+        assert_eq!(call_metrics.len(), trace.call_metrics().len() + 1);
+        let inclusion = call_metrics.last().unwrap();
+        assert_eq!(inclusion.program_id.to_string(), "inclusion.aleo");
+        assert_eq!(inclusion.function_name.to_string(), "inclusion");
+        assert!(inclusion.num_function_constraints > 0);
+
+        // Construct and prepare a trace for a function without a record input.
+        let (_, mut trace) = sample_trace("noop", Value::from_str("1u64").unwrap(), rng);
+        trace.prepare(MockQuery { state_path }).unwrap();
+
+        // Ensure there are no inclusion metrics, as there are no inclusion assignments.
+        let call_metrics = trace.call_metrics_with_inclusion::<CurrentAleo>().unwrap();
+        assert_eq!(call_metrics.len(), trace.call_metrics().len());
+    }
+}
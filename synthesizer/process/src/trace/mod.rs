@@ -15,20 +15,24 @@
 mod call_metrics;
 pub use call_metrics::*;
 
+mod event;
+pub use event::*;
+
 mod inclusion;
 pub use inclusion::*;
 
 use circuit::Assignment;
 use console::{
     network::prelude::*,
-    program::{InputID, Locator},
+    program::{Identifier, InputID, Locator, ProgramID, TransitionLeaf, TransitionPath},
+    types::Field,
 };
-use ledger_block::{Execution, Fee, Transition};
+use ledger_block::{Execution, Fee, Output, Transition};
 use ledger_query::QueryTrait;
 use synthesizer_snark::{Proof, ProvingKey, VerifyingKey};
 
 use once_cell::sync::OnceCell;
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Instant};
 
 #[derive(Clone, Debug, Default)]
 pub struct Trace<N: Network> {
@@ -40,6 +44,10 @@ pub struct Trace<N: Network> {
     inclusion_tasks: Inclusion<N>,
     /// A list of call metrics.
     call_metrics: Vec<CallMetrics<N>>,
+    /// A list of call metrics for the closure calls, in the order they were executed.
+    closure_metrics: Vec<CallMetrics<N>>,
+    /// The deadline of the execution, if any.
+    deadline: Option<Instant>,
 
     /// A tracker for the inclusion assignments.
     inclusion_assignments: OnceCell<Vec<InclusionAssignment<N>>>,
@@ -57,6 +65,8 @@ impl<N: Network> Trace<N> {
             inclusion_assignments: OnceCell::new(),
             global_state_root: OnceCell::new(),
             call_metrics: Vec::new(),
+            closure_metrics: Vec::new(),
+            deadline: None,
         }
     }
 
@@ -65,10 +75,59 @@ impl<N: Network> Trace<N> {
         &self.transitions
     }
 
+    /// Returns the circuit assignments for the given locator, in the order the transitions were inserted.
+    pub fn assignments(&self, locator: &Locator<N>) -> Option<&[Assignment<N::Field>]> {
+        self.transition_tasks.get(locator).map(|(_, assignments)| assignments.as_slice())
+    }
+
     /// Returns the call metrics.
     pub fn call_metrics(&self) -> &[CallMetrics<N>] {
         &self.call_metrics
     }
+
+    /// Returns the call metrics for the closure calls, in the order they were executed.
+    /// Note: A closure is synthesized in the circuit of its calling function, so its constraints are
+    /// also counted in the function constraints of the calling function in `call_metrics`.
+    pub fn closure_metrics(&self) -> &[CallMetrics<N>] {
+        &self.closure_metrics
+    }
+
+    /// Returns the events emitted by the functions, which are the public outputs of the transitions, in order.
+    pub fn events(&self) -> Vec<Event<N>> {
+        self.transitions.iter().flat_map(Event::from_transition).collect()
+    }
+}
+
+impl<N: Network> Trace<N> {
+    /// Returns the call metrics, along with an entry for the inclusion assignments (if there are any).
+    /// The inclusion entry is reported under the locator `inclusion.aleo/inclusion`, and counts the
+    /// constraints of all inclusion circuits as function constraints.
+    /// Note: This method synthesizes the inclusion circuits, and requires `prepare` to have been called.
+    pub fn call_metrics_with_inclusion<A: circuit::Aleo<Network = N>>(&self) -> Result<Vec<CallMetrics<N>>> {
+        // Retrieve the inclusion assignments.
+        let inclusion_assignments =
+            self.inclusion_assignments.get().ok_or_else(|| anyhow!("Inclusion assignments have not been set"))?;
+        // Initialize the call metrics.
+        let mut call_metrics = self.call_metrics.clone();
+        // If there are inclusion assignments, append the inclusion metrics.
+        if !inclusion_assignments.is_empty() {
+            // Count the constraints of each inclusion circuit.
+            let num_inclusion_constraints = inclusion_assignments
+                .iter()
+                .map(|assignment| Ok(assignment.to_circuit_assignment::<A>()?.num_constraints()))
+                .sum::<Result<u64>>()?;
+            // Append the inclusion metrics.
+            call_metrics.push(CallMetrics {
+                program_id: ProgramID::from_str("inclusion.aleo")?,
+                function_name: Identifier::from_str("inclusion")?,
+                num_instructions: 0,
+                num_request_constraints: 0,
+                num_function_constraints: num_inclusion_constraints,
+                num_response_constraints: 0,
+            });
+        }
+        Ok(call_metrics)
+    }
 }
 
 impl<N: Network> Trace<N> {
@@ -98,6 +157,24 @@ impl<N: Network> Trace<N> {
 
         Ok(())
     }
+
+    /// Inserts the call metrics of a closure call into the trace.
+    pub fn insert_closure_metrics(&mut self, metrics: CallMetrics<N>) {
+        self.closure_metrics.push(metrics);
+    }
+
+    /// Sets the deadline of the execution.
+    pub(crate) fn set_deadline(&mut self, deadline: Instant) {
+        self.deadline = Some(deadline);
+    }
+
+    /// Ensures the deadline of the execution (if any) has not passed.
+    pub(crate) fn check_deadline(&self) -> Result<()> {
+        match self.deadline {
+            Some(deadline) if Instant::now() >= deadline => bail!("Execution timed out"),
+            _ => Ok(()),
+        }
+    }
 }
 
 impl<N: Network> Trace<N> {
@@ -119,6 +196,46 @@ impl<N: Network> Trace<N> {
     }
 }
 
+impl<N: Network> Trace<N> {
+    /// Returns the transition leaf and Merkle path of the output at `output_index`,
+    /// in the transition at `transition_index`, against the root of that transition.
+    pub fn prove_output(
+        &self,
+        transition_index: usize,
+        output_index: usize,
+    ) -> Result<(TransitionLeaf<N>, TransitionPath<N>)> {
+        // Retrieve the transition.
+        let Some(transition) = self.transitions.get(transition_index) else {
+            bail!("Transition index {transition_index} is out of bounds ({} transitions)", self.transitions.len())
+        };
+        // Retrieve the output.
+        let Some(output) = transition.outputs().get(output_index) else {
+            bail!("Output index {output_index} is out of bounds ({} outputs)", transition.outputs().len())
+        };
+        // Compute the transition leaf.
+        let leaf = transition.to_leaf(output.id(), false)?;
+        // Compute the Merkle path.
+        let path = transition.to_path(&leaf)?;
+        Ok((leaf, path))
+    }
+
+    /// Returns `true` if the given transition leaf and Merkle path prove that the output is in the transition
+    /// with the given transition root.
+    pub fn verify_output_proof(
+        transition_root: &Field<N>,
+        output: &Output<N>,
+        leaf: &TransitionLeaf<N>,
+        path: &TransitionPath<N>,
+    ) -> bool {
+        // Ensure the leaf commits to the output.
+        if leaf.variant() != output.variant() || leaf.id() != *output.id() {
+            return false;
+        }
+        // Ensure the Merkle path is valid.
+        N::verify_merkle_path_bhp(path, transition_root, &leaf.to_bits_le())
+    }
+}
+
 impl<N: Network> Trace<N> {
     /// Returns the inclusion assignments and global state root for the current transition(s).
     pub fn prepare(&mut self, query: impl QueryTrait<N>) -> Result<()> {
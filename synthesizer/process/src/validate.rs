@@ -0,0 +1,301 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::{PlaintextType, RegisterType, Request};
+use ledger_block::Output;
+use synthesizer_program::{InstructionTrait, StackMatches};
+
+use std::collections::HashSet;
+
+/// A validation failure of a request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProcessError {
+    /// The program of the request does not exist in the process.
+    MissingProgram(String),
+    /// The function of the request does not exist in the program.
+    MissingFunction(String),
+    /// The number of inputs does not match the function (expected, found).
+    InputCount(usize, usize),
+    /// The number of input IDs does not match the number of inputs (expected, found).
+    InputIDCount(usize, usize),
+    /// The input at the given index does not match its declared type.
+    InputType(usize, String),
+    /// The signature of the request is invalid.
+    InvalidSignature,
+}
+
+impl Display for ProcessError {
+    /// Prints the validation failure as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::MissingProgram(program_id) => write!(f, "Program '{program_id}' does not exist"),
+            Self::MissingFunction(locator) => write!(f, "Function '{locator}' does not exist"),
+            Self::InputCount(expected, found) => write!(f, "Expected {expected} inputs, found {found}"),
+            Self::InputIDCount(expected, found) => write!(f, "Expected {expected} input IDs, found {found}"),
+            Self::InputType(index, error) => write!(f, "Input {index} does not match its type - {error}"),
+            Self::InvalidSignature => write!(f, "Request is invalid"),
+        }
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Validates the given request, and returns every validation failure (rather than only the first).
+    /// Note: As a request does not indicate its position in the call graph, the signature is accepted
+    /// if it is valid for either a root request or a child request.
+    pub fn validate_request(&self, request: &Request<N>) -> Vec<ProcessError> {
+        // Retrieve the stack.
+        let stack = match self.get_stack(request.program_id()) {
+            Ok(stack) => stack,
+            Err(_) => return vec![ProcessError::MissingProgram(request.program_id().to_string())],
+        };
+        // Retrieve the function.
+        let function = match stack.get_function_ref(request.function_name()) {
+            Ok(function) => function,
+            Err(_) => {
+                let locator = Locator::new(*request.program_id(), *request.function_name());
+                return vec![ProcessError::MissingFunction(locator.to_string())];
+            }
+        };
+
+        let mut errors = Vec::new();
+        // Ensure the number of inputs matches the function.
+        let input_types = function.input_types();
+        if request.inputs().len() != input_types.len() {
+            errors.push(ProcessError::InputCount(input_types.len(), request.inputs().len()));
+        }
+        // Ensure the number of input IDs matches the number of inputs.
+        if request.input_ids().len() != request.inputs().len() {
+            errors.push(ProcessError::InputIDCount(request.inputs().len(), request.input_ids().len()));
+        }
+        // Ensure each input matches its declared type.
+        for (index, (input, input_type)) in request.inputs().iter().zip(&input_types).enumerate() {
+            if let Err(error) = stack.matches_value_type(input, input_type) {
+                errors.push(ProcessError::InputType(index, error.to_string()));
+            }
+        }
+        // Ensure the signature is valid.
+        // Note: The signature can only be checked if the number of inputs and input IDs are correct.
+        if errors.iter().all(|error| !matches!(error, ProcessError::InputCount(..) | ProcessError::InputIDCount(..)))
+            && !request.verify(&input_types, true)
+            && !request.verify(&input_types, false)
+        {
+            errors.push(ProcessError::InvalidSignature);
+        }
+        errors
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Validates that every external program, `call` target, struct, and record referenced by the given program
+    /// is defined, either in the program itself or in a program loaded in the process.
+    /// On failure, the error lists every undefined reference (rather than only the first).
+    pub fn validate_program(&self, program: &Program<N>) -> Result<()> {
+        let mut errors = Vec::new();
+
+        // Ensure the imported programs are loaded.
+        for import_id in program.imports().keys() {
+            if !self.contains_program(import_id) {
+                errors.push(format!("Imported program '{import_id}' does not exist"));
+            }
+        }
+
+        // Ensure the types and call targets of the closures are defined.
+        for closure in program.closures().values() {
+            let inputs = closure.inputs().iter().map(|input| input.register_type().clone());
+            let outputs = closure.outputs().iter().map(|output| output.register_type().clone());
+            for register_type in inputs.chain(outputs) {
+                errors.extend(self.undefined_register_type(program, &register_type));
+            }
+            for instruction in closure.instructions() {
+                errors.extend(self.undefined_call_target(program, instruction));
+            }
+        }
+
+        // Ensure the types and call targets of the functions are defined.
+        for function in program.functions().values() {
+            for value_type in function.input_types().into_iter().chain(function.output_types()) {
+                errors.extend(self.undefined_register_type(program, &RegisterType::from(value_type)));
+            }
+            for instruction in function.instructions() {
+                errors.extend(self.undefined_call_target(program, instruction));
+            }
+        }
+
+        ensure!(errors.is_empty(), "Program '{}' has undefined references:\n{}", program.id(), errors.join("\n"));
+        Ok(())
+    }
+
+    /// Returns an error message if the given register type references an undefined struct, record, or function.
+    fn undefined_register_type(&self, program: &Program<N>, register_type: &RegisterType<N>) -> Option<String> {
+        match register_type {
+            RegisterType::Plaintext(plaintext_type) => Self::undefined_plaintext_type(program, plaintext_type),
+            RegisterType::Record(record_name) => match program.contains_record(record_name) {
+                true => None,
+                false => Some(format!("Record '{record_name}' is not defined")),
+            },
+            RegisterType::ExternalRecord(locator) => match self.get_program(locator.program_id()) {
+                Ok(external) if external.contains_record(locator.resource()) => None,
+                _ => Some(format!("External record '{locator}' is not defined")),
+            },
+            RegisterType::Future(locator) => match self.contains_resource(program, locator) {
+                true => None,
+                false => Some(format!("Function '{locator}' of the future is not defined")),
+            },
+        }
+    }
+
+    /// Returns an error message if the given plaintext type references an undefined struct.
+    fn undefined_plaintext_type(program: &Program<N>, plaintext_type: &PlaintextType<N>) -> Option<String> {
+        match plaintext_type {
+            PlaintextType::Literal(..) => None,
+            PlaintextType::Struct(struct_name) => match program.contains_struct(struct_name) {
+                true => None,
+                false => Some(format!("Struct '{struct_name}' is not defined")),
+            },
+            PlaintextType::Array(array_type) => Self::undefined_plaintext_type(program, array_type.base_element_type()),
+        }
+    }
+
+    /// Returns an error message if the given instruction is a `call` to an undefined closure or function.
+    fn undefined_call_target(&self, program: &Program<N>, instruction: &Instruction<N>) -> Option<String> {
+        match instruction {
+            Instruction::Call(call) => match call.operator() {
+                CallOperator::Locator(locator) => match self.contains_resource(program, locator) {
+                    true => None,
+                    false => Some(format!("Call target '{locator}' is not defined")),
+                },
+                CallOperator::Resource(resource) => {
+                    match program.contains_closure(resource) || program.contains_function(resource) {
+                        true => None,
+                        false => Some(format!("Call target '{resource}' is not defined")),
+                    }
+                }
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the closure or function at the given locator is defined,
+    /// either in the given program or in a program loaded in the process.
+    fn contains_resource(&self, program: &Program<N>, locator: &Locator<N>) -> bool {
+        let resource = locator.resource();
+        match locator.program_id() == program.id() {
+            true => program.contains_closure(resource) || program.contains_function(resource),
+            false => match self.get_program(locator.program_id()) {
+                Ok(external) => external.contains_closure(resource) || external.contains_function(resource),
+                Err(_) => false,
+            },
+        }
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Validates that the given transition outputs match the output types declared by the function,
+    /// i.e. a `public` output is a public output, and a record output is a record output.
+    pub fn validate_outputs(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        outputs: &[Output<N>],
+    ) -> Result<()> {
+        // Prepare the program ID.
+        let program_id = program_id.try_into().map_err(|_| anyhow!("Invalid program ID"))?;
+        // Prepare the function name.
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+        // Retrieve the function.
+        let function = self.get_stack(program_id)?.get_function(&function_name)?;
+        let locator = Locator::new(program_id, function_name);
+
+        // Ensure the number of outputs matches the function.
+        let output_types = function.output_types();
+        ensure!(
+            output_types.len() == outputs.len(),
+            "Expected {} outputs for '{locator}', found {}",
+            output_types.len(),
+            outputs.len()
+        );
+
+        // Ensure each output matches its declared output type.
+        for (index, (output, output_type)) in outputs.iter().zip_eq(&output_types).enumerate() {
+            let is_match = matches!(
+                (output, output_type),
+                (Output::Constant(..), ValueType::Constant(..))
+                    | (Output::Public(..), ValueType::Public(..))
+                    | (Output::Private(..), ValueType::Private(..))
+                    | (Output::Record(..), ValueType::Record(..))
+                    | (Output::ExternalRecord(..), ValueType::ExternalRecord(..))
+                    | (Output::Future(..), ValueType::Future(..))
+            );
+            ensure!(is_match, "Output {index} of '{locator}' does not match its declared type '{output_type}'");
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Validates that every `constant` output of the given function is a compile-time constant,
+    /// i.e. it does not depend on a non-constant input, `self.signer`, or `self.caller`.
+    /// Note: The hash of a constant output is injected as a constant in the circuit, so an output that depends
+    /// on a non-constant value would change the circuit (and hence the verifying key) across executions.
+    pub fn validate_constant_outputs(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+    ) -> Result<()> {
+        // Prepare the program ID.
+        let program_id = program_id.try_into().map_err(|_| anyhow!("Invalid program ID"))?;
+        // Prepare the function name.
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+        // Retrieve the function.
+        let function = self.get_stack(program_id)?.get_function(&function_name)?;
+        let locator = Locator::new(program_id, function_name);
+
+        // Initialize the set of registers that depend on a non-constant value, from the non-constant inputs.
+        let mut dependents = function
+            .inputs()
+            .iter()
+            .filter(|input| !matches!(input.value_type(), ValueType::Constant(..)))
+            .map(|input| input.register().locator())
+            .collect::<HashSet<_>>();
+        // Returns `true` if the operand depends on a non-constant value.
+        let is_dependent = |dependents: &HashSet<u64>, operand: &Operand<N>| match operand {
+            Operand::Register(register) => dependents.contains(&register.locator()),
+            Operand::Signer | Operand::Caller => true,
+            _ => false,
+        };
+
+        // Propagate the dependencies through the instructions.
+        // Note: The destinations of a `call` are always treated as non-constant, as the callee may depend on
+        // a non-constant value that is not an operand of the call (e.g. `self.caller` in an external function).
+        for instruction in function.instructions() {
+            if matches!(instruction, Instruction::Call(..))
+                || instruction.operands().iter().any(|operand| is_dependent(&dependents, operand))
+            {
+                dependents.extend(instruction.destinations().iter().map(|register| register.locator()));
+            }
+        }
+
+        // Ensure each constant output does not depend on a non-constant value.
+        for (index, output) in function.outputs().iter().enumerate() {
+            if let ValueType::Constant(..) = output.value_type() {
+                ensure!(
+                    !is_dependent(&dependents, output.operand()),
+                    "Constant output {index} of '{locator}' depends on a non-constant value"
+                );
+            }
+        }
+        Ok(())
+    }
+}
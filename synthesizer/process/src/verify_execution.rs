@@ -92,12 +92,7 @@ impl<N: Network> Process<N> {
             // Ensure each output is valid.
             let num_inputs = transition.inputs().len();
             let num_outputs = transition.outputs().len();
-            if transition
-                .outputs()
-                .iter()
-                .enumerate()
-                .any(|(index, output)| !output.verify(function_id, transition.tcm(), num_inputs + index))
-            {
+            if !transition.verify_outputs(function_id) {
                 bail!("Failed to verify a transition output")
             }
             lap!(timer, "Verify the outputs");
@@ -202,7 +197,7 @@ impl<N: Network> Process<N> {
         }
 
         // [Inputs] Extend the verifier inputs with the output IDs.
-        inputs.extend(transition.outputs().iter().flat_map(|output| output.verifier_inputs()));
+        inputs.extend(PublicInputs::from_outputs(transition.outputs()).to_vec());
 
         #[cfg(debug_assertions)]
         println!("Transition public inputs ({} elements): {:#?}", inputs.len(), inputs);
@@ -102,12 +102,7 @@ impl<N: Network> Process<N> {
             fee.outputs().len()
         );
         // Ensure each output is valid.
-        if fee
-            .outputs()
-            .iter()
-            .enumerate()
-            .any(|(index, output)| !output.verify(function_id, fee.tcm(), num_inputs + index))
-        {
+        if !fee.verify_outputs(function_id) {
             bail!("Failed to verify a fee output")
         }
         lap!(timer, "Verify the outputs");
@@ -125,7 +120,7 @@ impl<N: Network> Process<N> {
         // Extend the verifier inputs with the public inputs for 'self.caller'.
         inputs.extend([*Field::<N>::one(), *parent_x, *parent_y]);
         // Extend the inputs with the output IDs.
-        inputs.extend(fee.outputs().iter().flat_map(|output| output.verifier_inputs()));
+        inputs.extend(PublicInputs::from_outputs(fee.outputs()).to_vec());
         lap!(timer, "Construct the verifier inputs");
 
         #[cfg(debug_assertions)]
@@ -171,12 +166,7 @@ impl<N: Network> Process<N> {
             fee.outputs().len()
         );
         // Ensure each output is valid.
-        if fee
-            .outputs()
-            .iter()
-            .enumerate()
-            .any(|(index, output)| !output.verify(function_id, fee.tcm(), num_inputs + index))
-        {
+        if !fee.verify_outputs(function_id) {
             bail!("Failed to verify a fee output")
         }
         lap!(timer, "Verify the outputs");
@@ -194,7 +184,7 @@ impl<N: Network> Process<N> {
         // Extend the verifier inputs with the public inputs for 'self.caller'
         inputs.extend([*Field::<N>::one(), *parent_x, *parent_y]);
         // Extend the inputs with the output IDs.
-        inputs.extend(fee.outputs().iter().flat_map(|output| output.verifier_inputs()));
+        inputs.extend(PublicInputs::from_outputs(fee.outputs()).to_vec());
         lap!(timer, "Construct the verifier inputs");
 
         #[cfg(debug_assertions)]
@@ -121,6 +121,19 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
     }
 }
 
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
+    /// Returns a program from the given reader.
+    /// Note: At most `N::MAX_PROGRAM_SIZE` bytes are read, so an oversized program is rejected
+    /// without reading the remainder of the reader.
+    pub fn from_reader<R: Read>(reader: R) -> Result<Self> {
+        // Read the program string, up to one byte past MAX_PROGRAM_SIZE.
+        let mut string = String::new();
+        reader.take(N::MAX_PROGRAM_SIZE as u64 + 1).read_to_string(&mut string)?;
+        // Parse the program string.
+        Self::from_str(&string)
+    }
+}
+
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Debug
     for ProgramCore<N, Instruction, Command>
 {
@@ -238,6 +251,27 @@ function compute:
         Ok(())
     }
 
+    #[test]
+    fn test_program_from_reader() -> Result<()> {
+        let expected = r"program to_parse.aleo;
+
+function compute:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;
+";
+        // Parse a new program from a reader.
+        let program = Program::<CurrentNetwork>::from_reader(std::io::Cursor::new(expected))?;
+        // Ensure the program matches the program parsed from the string.
+        assert_eq!(program, Program::<CurrentNetwork>::from_str(expected)?);
+
+        // Ensure an oversized program is rejected.
+        let oversized = format!("{expected}{}", " ".repeat(CurrentNetwork::MAX_PROGRAM_SIZE));
+        assert!(Program::<CurrentNetwork>::from_reader(oversized.as_bytes()).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_display() -> Result<()> {
         let expected = r"program to_parse.aleo;
@@ -16,6 +16,9 @@
 
 use super::*;
 
+use console::program::InputID;
+use rand_chacha::ChaChaRng;
+
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Returns a new execute transaction.
     ///
@@ -94,6 +97,93 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         Transaction::from_execution(execution, fee)
     }
 
+    /// Returns a new execute transaction for the given authorization, with a fee of `base_fee_in_microcredits`.
+    ///
+    /// If a `fee_record` is provided, then a private fee will be included in the transaction;
+    /// otherwise, a public fee will be included in the transaction.
+    ///
+    /// Unlike `execute`, the fee is authorized upfront, so the execution and fee are executed together,
+    /// and their inclusion assignments are prepared against a single fetch of the global state root.
+    pub fn execute_with_fee<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        authorization: Authorization<N>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        base_fee_in_microcredits: u64,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        let timer = timer!("VM::execute_with_fee");
+
+        // Construct the locator of the main function.
+        let locator = {
+            let request = authorization.peek_next()?;
+            Locator::new(*request.program_id(), *request.function_name()).to_string()
+        };
+        // Compute the execution ID.
+        let execution_id = authorization.to_execution_id()?;
+        // Authorize the fee.
+        let fee_authorization = match fee_record {
+            Some(record) => self.authorize_fee_private(
+                private_key,
+                record,
+                base_fee_in_microcredits,
+                priority_fee_in_microcredits,
+                execution_id,
+                rng,
+            )?,
+            None => self.authorize_fee_public(
+                private_key,
+                base_fee_in_microcredits,
+                priority_fee_in_microcredits,
+                execution_id,
+                rng,
+            )?,
+        };
+        lap!(timer, "Authorize the fee");
+
+        // Prepare the query, by pinning the global state root for both the execution and the fee.
+        let query = PinnedQuery::new(match query {
+            Some(query) => query,
+            None => Query::VM(self.block_store().clone()),
+        })?;
+        lap!(timer, "Prepare the query");
+
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the authorizations.
+                let authorization = cast_ref!(authorization as Authorization<$network>);
+                let fee_authorization = cast_ref!(fee_authorization as Authorization<$network>);
+                // Execute the calls.
+                let (_, mut execution_trace) = $process.execute::<$aleo, _>(authorization.clone(), rng)?;
+                let (_, mut fee_trace) = $process.execute::<$aleo, _>(fee_authorization.clone(), rng)?;
+                lap!(timer, "Execute the calls");
+
+                // Prepare the assignments.
+                cast_mut_ref!(execution_trace as Trace<N>).prepare(query.clone())?;
+                cast_mut_ref!(fee_trace as Trace<N>).prepare(query)?;
+                lap!(timer, "Prepare the assignments");
+
+                // Compute the proofs and construct the execution and fee.
+                let execution = execution_trace.prove_execution::<$aleo, _>(&locator, rng)?;
+                let fee = fee_trace.prove_fee::<$aleo, _>(rng)?;
+                lap!(timer, "Compute the proofs");
+
+                // Return the execution and fee.
+                Ok((cast_ref!(execution as Execution<N>).clone(), cast_ref!(fee as Fee<N>).clone()))
+            }};
+        }
+
+        // Execute the authorizations.
+        let result: Result<(Execution<N>, Fee<N>)> = process!(self, logic);
+        let (execution, fee) = result?;
+        finish!(timer, "Execute the authorizations");
+
+        // Return the execute transaction.
+        Transaction::from_execution(execution, Some(fee))
+    }
+
     /// Returns a new fee for the given authorization.
     pub fn execute_fee_authorization<R: Rng + CryptoRng>(
         &self,
@@ -104,6 +194,344 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         debug_assert!(authorization.is_fee_private() || authorization.is_fee_public(), "Expected a fee authorization");
         self.execute_fee_authorization_raw(authorization, query, rng)
     }
+
+    /// Returns a new fee for the given authorization, along with the commitment and (encrypted) change record
+    /// of the fee, if the fee is private. This allows a wallet to track the change record without rescanning.
+    #[allow(clippy::type_complexity)]
+    pub fn execute_fee_authorization_with_change<R: Rng + CryptoRng>(
+        &self,
+        authorization: Authorization<N>,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<(Fee<N>, Option<(Field<N>, Record<N, Ciphertext<N>>)>)> {
+        // Execute the fee.
+        let fee = self.execute_fee_authorization(authorization, query, rng)?;
+        // Retrieve the change record, if the fee is private.
+        let change = fee.change_record().map(|(commitment, record)| (*commitment, record.clone()));
+        Ok((fee, change))
+    }
+
+    /// Returns a new fee for the given authorization, and invokes `audit` with the commitment of the spent fee record
+    /// and the fee amount in microcredits, if the fee is private. The audit is only invoked once the fee is produced.
+    /// Note: The audit is informational, and does not change the fee.
+    pub fn execute_fee_authorization_with_audit<R: Rng + CryptoRng>(
+        &self,
+        authorization: Authorization<N>,
+        query: Option<Query<N, C::BlockStorage>>,
+        audit: impl FnOnce(Field<N>, u64),
+        rng: &mut R,
+    ) -> Result<Fee<N>> {
+        // Retrieve the commitment of the fee record, if the fee is private.
+        let commitment = match authorization.is_fee_private() {
+            true => match authorization.peek_next()?.input_ids().first() {
+                Some(InputID::Record(commitment, ..)) => Some(*commitment),
+                _ => bail!("Expected a record input in the private fee authorization"),
+            },
+            false => None,
+        };
+        // Execute the fee.
+        let fee = self.execute_fee_authorization(authorization, query, rng)?;
+        // Audit the spent fee record.
+        if let Some(commitment) = commitment {
+            audit(commitment, *fee.amount()?);
+        }
+        Ok(fee)
+    }
+
+    /// Returns a new fee for the given authorization, if the fee does not exceed `max_fee_in_microcredits`.
+    /// Note: The fee is checked before it is executed, so an excessive fee is rejected without being proven.
+    pub fn execute_fee_authorization_with_cap<R: Rng + CryptoRng>(
+        &self,
+        authorization: Authorization<N>,
+        max_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Fee<N>> {
+        // Determine the input index of the base fee, which follows the fee record if the fee is private.
+        let base_fee_index = match (authorization.is_fee_private(), authorization.is_fee_public()) {
+            (true, _) => 1,
+            (_, true) => 0,
+            _ => bail!("Expected a fee authorization"),
+        };
+        // Retrieve the fee (in microcredits) at the given input index.
+        let request = authorization.peek_next()?;
+        let fee_at = |index: usize| match request.inputs().get(index) {
+            Some(Value::Plaintext(Plaintext::Literal(Literal::U64(microcredits), _))) => Ok(**microcredits),
+            _ => bail!("Failed to retrieve the fee (in microcredits) from the fee authorization"),
+        };
+        // Compute the total fee.
+        let Some(fee_in_microcredits) = fee_at(base_fee_index)?.checked_add(fee_at(base_fee_index + 1)?) else {
+            bail!("The fee overflowed for the fee authorization")
+        };
+        // Ensure the fee does not exceed the maximum fee.
+        ensure!(
+            fee_in_microcredits <= max_fee_in_microcredits,
+            "The fee of {fee_in_microcredits} microcredits exceeds the maximum fee of {max_fee_in_microcredits} microcredits"
+        );
+        // Execute the fee.
+        self.execute_fee_authorization(authorization, query, rng)
+    }
+
+    /// Returns a new public fee of `base_fee_in_microcredits + priority_fee_in_microcredits`,
+    /// to be debited from the `credits.aleo` account balance of the given private key.
+    ///
+    /// This ensures the public balance is sufficient before authorizing, executing, and proving the fee.
+    pub fn execute_fee_from_public<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        base_fee_in_microcredits: u64,
+        priority_fee_in_microcredits: u64,
+        deployment_or_execution_id: Field<N>,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Fee<N>> {
+        // Compute the total fee.
+        let Some(fee_in_microcredits) = base_fee_in_microcredits.checked_add(priority_fee_in_microcredits) else {
+            bail!("The fee overflowed for a public fee")
+        };
+
+        // Retrieve the public balance of the payer.
+        let payer = Address::try_from(private_key)?;
+        let balance = match self.finalize_store().get_value_speculative(
+            ProgramID::from_str("credits.aleo")?,
+            Identifier::from_str("account")?,
+            &Plaintext::from(Literal::Address(payer)),
+        )? {
+            Some(Value::Plaintext(Plaintext::Literal(Literal::U64(balance), _))) => *balance,
+            _ => bail!("The account '{payer}' does not have a public balance in 'credits.aleo'"),
+        };
+        // Ensure the balance is sufficient to pay the fee.
+        ensure!(
+            balance >= fee_in_microcredits,
+            "The public balance ({balance} microcredits) is insufficient to pay the fee ({fee_in_microcredits} microcredits)"
+        );
+
+        // Authorize the public fee.
+        let authorization = self.authorize_fee_public(
+            private_key,
+            base_fee_in_microcredits,
+            priority_fee_in_microcredits,
+            deployment_or_execution_id,
+            rng,
+        )?;
+        // Execute the fee.
+        self.execute_fee_authorization_raw(authorization, query, rng)
+    }
+
+    /// Returns a new fee of `base_fee_in_microcredits + priority_fee_in_microcredits`, using an RNG seeded from `seed`.
+    ///
+    /// If a `fee_record` is provided, then a private fee is produced; otherwise, a public fee is produced.
+    /// For the same inputs and ledger state, the same seed produces the same fee, which makes the fee reproducible.
+    ///
+    /// Note: The RNG samples the signature nonce of the fee request, and reusing a nonce across two different
+    /// messages reveals the private key. To prevent this, the RNG is seeded with `Hash(seed || private key || fee inputs)`,
+    /// so a seed that is reused for a different fee produces a different nonce. The seed must still be kept secret,
+    /// as anyone who knows it (and the fee inputs) can recompute the nonces of the fee.
+    pub fn execute_fee_seeded(
+        &self,
+        private_key: &PrivateKey<N>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        base_fee_in_microcredits: u64,
+        priority_fee_in_microcredits: u64,
+        deployment_or_execution_id: Field<N>,
+        query: Option<Query<N, C::BlockStorage>>,
+        seed: [u8; 32],
+    ) -> Result<Fee<N>> {
+        // Construct the preimage of the RNG seed, which binds the seed to the private key and the fee inputs.
+        let mut preimage = to_bits_le![
+            Field::<N>::new_domain_separator("AleoFeeSeed"),
+            seed,
+            private_key.seed(),
+            base_fee_in_microcredits,
+            priority_fee_in_microcredits,
+            deployment_or_execution_id
+        ];
+        if let Some(record) = &fee_record {
+            record.write_bits_le(&mut preimage);
+        }
+
+        // Hash the preimage.
+        let digest = N::hash_bhp1024(&preimage)?.to_bytes_le()?;
+        // Ensure the digest is 32-bytes.
+        ensure!(digest.len() == 32, "The digest for the ChaChaRng seed must be 32-bytes");
+
+        // Construct the ChaChaRng seed.
+        let mut chacha_seed = [0u8; 32];
+        chacha_seed.copy_from_slice(&digest[..32]);
+
+        // Initialize the RNG from the derived seed.
+        let rng = &mut ChaChaRng::from_seed(chacha_seed);
+        // Authorize the fee.
+        let authorization = match fee_record {
+            Some(record) => self.authorize_fee_private(
+                private_key,
+                record,
+                base_fee_in_microcredits,
+                priority_fee_in_microcredits,
+                deployment_or_execution_id,
+                rng,
+            )?,
+            None => self.authorize_fee_public(
+                private_key,
+                base_fee_in_microcredits,
+                priority_fee_in_microcredits,
+                deployment_or_execution_id,
+                rng,
+            )?,
+        };
+        // Execute the fee.
+        self.execute_fee_authorization_raw(authorization, query, rng)
+    }
+
+    /// Returns an estimate of a fee of `base_fee_in_microcredits + priority_fee_in_microcredits`.
+    ///
+    /// If a `fee_record` is provided, then a private fee is estimated; otherwise, a public fee is estimated.
+    /// This synthesizes the fee transition, but does not prepare the inclusion assignments or compute the proof,
+    /// so it does not require a query to the block store.
+    pub fn simulate_fee<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        base_fee_in_microcredits: u64,
+        priority_fee_in_microcredits: u64,
+        deployment_or_execution_id: Field<N>,
+        rng: &mut R,
+    ) -> Result<FeeEstimate> {
+        // Compute the fee amount.
+        let Some(amount_in_microcredits) = base_fee_in_microcredits.checked_add(priority_fee_in_microcredits) else {
+            bail!("The fee overflowed for an estimated fee")
+        };
+        // Authorize the fee.
+        let authorization = match fee_record {
+            Some(record) => self.authorize_fee_private(
+                private_key,
+                record,
+                base_fee_in_microcredits,
+                priority_fee_in_microcredits,
+                deployment_or_execution_id,
+                rng,
+            )?,
+            None => self.authorize_fee_public(
+                private_key,
+                base_fee_in_microcredits,
+                priority_fee_in_microcredits,
+                deployment_or_execution_id,
+                rng,
+            )?,
+        };
+
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the authorization.
+                let authorization = cast_ref!(authorization as Authorization<$network>);
+                // Execute the call.
+                let (_, trace) = $process.execute::<$aleo, _>(authorization.clone(), rng)?;
+                let trace = cast_ref!(trace as Trace<N>);
+
+                // Retrieve the fee transition.
+                let Some(transition) = trace.transitions().first() else { bail!("Missing the fee transition") };
+                // Count the constraints of the fee circuit.
+                let num_constraints = trace
+                    .call_metrics()
+                    .iter()
+                    .map(|metrics| {
+                        metrics.num_request_constraints
+                            + metrics.num_function_constraints
+                            + metrics.num_response_constraints
+                    })
+                    .sum();
+
+                // Return the fee estimate.
+                Ok(FeeEstimate {
+                    amount_in_microcredits,
+                    transition_size_in_bytes: transition.to_bytes_le()?.len(),
+                    num_constraints,
+                })
+            }};
+        }
+
+        // Execute the authorization.
+        process!(self, logic)
+    }
+
+    /// Checks that the given fee record can pay a private fee of `fee_in_microcredits`.
+    ///
+    /// This performs the validations of a private fee (record ownership, record type, balance,
+    /// and record inclusion) without authorizing, executing, or proving the fee.
+    pub fn check_fee_feasibility(
+        &self,
+        private_key: &PrivateKey<N>,
+        fee_record: &Record<N, Plaintext<N>>,
+        fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+    ) -> Result<()> {
+        // Ensure the fee record belongs to the private key.
+        let address = Address::try_from(private_key)?;
+        ensure!(**fee_record.owner() == address, "The fee record does not belong to the given private key");
+
+        // Ensure the fee record is a 'credits.aleo' record, and retrieve its balance.
+        let balance = match fee_record.find(&[Identifier::from_str("microcredits")?]) {
+            Ok(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => *amount,
+            _ => bail!("The fee record is not a 'credits.aleo' record"),
+        };
+        // Ensure the balance is sufficient to pay the fee.
+        ensure!(
+            balance >= fee_in_microcredits,
+            "The fee record balance ({balance} microcredits) is insufficient to pay the fee ({fee_in_microcredits} microcredits)"
+        );
+
+        // Prepare the query.
+        let query = match query {
+            Some(query) => query,
+            None => Query::VM(self.block_store().clone()),
+        };
+        // Compute the commitment of the fee record.
+        let commitment =
+            fee_record.to_commitment(&ProgramID::from_str("credits.aleo")?, &Identifier::from_str("credits")?)?;
+        // Ensure the fee record exists in the ledger.
+        if let Err(error) = query.get_state_path_for_commitment(&commitment) {
+            bail!("The fee record does not exist in the ledger - {error}")
+        }
+        // Ensure the fee record has not been spent.
+        let serial_number = Record::<N, Plaintext<N>>::serial_number(*private_key, commitment)?;
+        ensure!(!self.transition_store().contains_serial_number(&serial_number)?, "The fee record is already spent");
+
+        Ok(())
+    }
+
+    /// Returns the unspent `credits.aleo` records in the ledger that belong to the given view key.
+    ///
+    /// As a view key can not derive serial numbers, a record is considered spent if its tag
+    /// (which is derived from the graph key of the view key) exists in the transition store.
+    pub fn spendable_records(&self, view_key: &ViewKey<N>) -> Result<IndexMap<Field<N>, Record<N, Plaintext<N>>>> {
+        // Derive the x-coordinate of the address corresponding to the given view key.
+        let address_x_coordinate = view_key.to_address().to_x_coordinate();
+        // Derive the `sk_tag` from the graph key.
+        let sk_tag = GraphKey::try_from(view_key)?.sk_tag();
+        // Prepare the 'credits.aleo' program ID.
+        let credits_id = ProgramID::from_str("credits.aleo")?;
+
+        let mut records = IndexMap::new();
+        for (commitment, record) in self.transition_store().records() {
+            // Skip the record if it does not belong to the view key.
+            if !record.is_owner_with_address_x_coordinate(view_key, &address_x_coordinate) {
+                continue;
+            }
+            // Skip the record if it is not a 'credits.aleo' record.
+            let transition_id = self.transition_store().find_transition_id(&commitment)?;
+            if self.transition_store().get_program_id(&transition_id)? != Some(credits_id) {
+                continue;
+            }
+            // Skip the record if it has been spent.
+            let tag = Record::<N, Plaintext<N>>::tag(sk_tag, *commitment)?;
+            if self.transition_store().contains_tag(&tag)? {
+                continue;
+            }
+            // Decrypt the record.
+            records.insert(*commitment, record.decrypt(view_key)?);
+        }
+        Ok(records)
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -611,6 +1039,329 @@ mod tests {
         assert_eq!(1416, fee_size_in_bytes, "Update me if serialization has changed");
     }
 
+    #[test]
+    fn test_check_fee_feasibility() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM and records.
+        let (vm, records) = prepare_vm(rng).unwrap();
+
+        // Fetch the unspent record.
+        let record = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
+
+        // Ensure a sufficient fee is feasible.
+        vm.check_fee_feasibility(&caller_private_key, &record, 1, None).unwrap();
+
+        // Ensure a record that belongs to another account is rejected.
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let error = vm.check_fee_feasibility(&other_private_key, &record, 1, None).unwrap_err();
+        assert!(error.to_string().contains("does not belong"), "{error}");
+
+        // Ensure a non-credits record is rejected.
+        let token = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {address}.private, token_amount: 100u64.private, _nonce: 0group.public }}"
+        ))
+        .unwrap();
+        let error = vm.check_fee_feasibility(&caller_private_key, &token, 1, None).unwrap_err();
+        assert!(error.to_string().contains("not a 'credits.aleo' record"), "{error}");
+
+        // Ensure an insufficient balance is rejected.
+        let error = vm.check_fee_feasibility(&caller_private_key, &record, u64::MAX, None).unwrap_err();
+        assert!(error.to_string().contains("insufficient"), "{error}");
+
+        // Ensure a record that does not exist in the ledger is rejected.
+        let credits = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {address}.private, microcredits: 100u64.private, _nonce: 0group.public }}"
+        ))
+        .unwrap();
+        let error = vm.check_fee_feasibility(&caller_private_key, &credits, 1, None).unwrap_err();
+        assert!(error.to_string().contains("does not exist in the ledger"), "{error}");
+    }
+
+    #[test]
+    fn test_execute_fee_from_public() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_address = Address::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM.
+        let (vm, _) = prepare_vm(rng).unwrap();
+
+        // Sample a deployment or execution ID.
+        let id = Field::<CurrentNetwork>::rand(rng);
+
+        // Ensure a public fee is produced for an account with a sufficient public balance.
+        let fee = vm.execute_fee_from_public(&caller_private_key, 100, 10, id, None, rng).unwrap();
+        assert!(fee.is_fee_public());
+        assert_eq!(fee.payer(), Some(caller_address));
+        assert_eq!(*fee.amount().unwrap(), 110);
+        assert_eq!(*fee.deployment_or_execution_id().unwrap(), *id);
+
+        // Ensure the fee is valid.
+        vm.check_fee(&Transaction::from_fee_checked(fee).unwrap(), Some(id)).unwrap();
+
+        // Ensure an insufficient public balance is rejected.
+        let error = vm.execute_fee_from_public(&caller_private_key, u64::MAX, 0, id, None, rng).unwrap_err();
+        assert!(error.to_string().contains("insufficient"), "{error}");
+
+        // Ensure an account without a public balance is rejected.
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let error = vm.execute_fee_from_public(&other_private_key, 1, 0, id, None, rng).unwrap_err();
+        assert!(error.to_string().contains("does not have a public balance"), "{error}");
+    }
+
+    #[test]
+    fn test_execute_fee_seeded() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+
+        // Prepare the VM.
+        let (vm, _) = prepare_vm(rng).unwrap();
+
+        // Sample a deployment or execution ID.
+        let id = Field::<CurrentNetwork>::rand(rng);
+
+        // Ensure two runs with the same seed produce byte-identical fees.
+        let fee = vm.execute_fee_seeded(&caller_private_key, None, 100, 10, id, None, [7u8; 32]).unwrap();
+        let expected = vm.execute_fee_seeded(&caller_private_key, None, 100, 10, id, None, [7u8; 32]).unwrap();
+        assert_eq!(fee.to_bytes_le().unwrap(), expected.to_bytes_le().unwrap());
+
+        // Ensure the fee is valid.
+        vm.check_fee(&Transaction::from_fee_checked(fee.clone()).unwrap(), Some(id)).unwrap();
+
+        // Ensure a different seed produces a different fee.
+        let other = vm.execute_fee_seeded(&caller_private_key, None, 100, 10, id, None, [8u8; 32]).unwrap();
+        assert_ne!(fee.transition().id(), other.transition().id());
+
+        // Ensure reusing the seed for a different fee does not reuse the signature nonce (i.e. `tpk`).
+        let other_id = Field::<CurrentNetwork>::rand(rng);
+        let other = vm.execute_fee_seeded(&caller_private_key, None, 100, 10, other_id, None, [7u8; 32]).unwrap();
+        assert_ne!(fee.transition().tpk(), other.transition().tpk());
+        let other = vm.execute_fee_seeded(&caller_private_key, None, 100, 11, id, None, [7u8; 32]).unwrap();
+        assert_ne!(fee.transition().tpk(), other.transition().tpk());
+    }
+
+    #[test]
+    fn test_simulate_fee() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+
+        // Prepare the VM.
+        let (vm, _) = prepare_vm(rng).unwrap();
+
+        // Sample a deployment or execution ID.
+        let id = Field::<CurrentNetwork>::rand(rng);
+
+        // Estimate the public fee.
+        let estimate = vm.simulate_fee(&caller_private_key, None, 100, 10, id, rng).unwrap();
+        assert_eq!(estimate.amount_in_microcredits, 110);
+        assert!(estimate.num_constraints > 0);
+
+        // Ensure the estimated size matches the size of the proven fee transition.
+        let fee = vm.execute_fee_from_public(&caller_private_key, 100, 10, id, None, rng).unwrap();
+        assert_eq!(estimate.transition_size_in_bytes, fee.transition().to_bytes_le().unwrap().len());
+        assert!(estimate.transition_size_in_bytes < fee.to_bytes_le().unwrap().len());
+    }
+
+    #[test]
+    fn test_execute_fee_authorization_with_change() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM and records.
+        let (vm, records) = prepare_vm(rng).unwrap();
+
+        // Fetch the unspent record, and its balance.
+        let record = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
+        let balance = match record.find(&[Identifier::from_str("microcredits").unwrap()]).unwrap() {
+            Entry::Private(Plaintext::Literal(Literal::U64(amount), _)) => *amount,
+            _ => panic!("Expected a 'credits.aleo' record"),
+        };
+
+        // Execute a private fee.
+        let authorization =
+            vm.authorize_fee_private(&caller_private_key, record, 100, 10, Field::rand(rng), rng).unwrap();
+        let (fee, change) = vm.execute_fee_authorization_with_change(authorization, None, rng).unwrap();
+        let (commitment, change) = change.unwrap();
+        assert_eq!(fee.change_record(), Some((&commitment, &change)));
+
+        // Ensure the change record decrypts to the remaining balance.
+        let change = change.decrypt(&caller_view_key).unwrap();
+        match change.find(&[Identifier::from_str("microcredits").unwrap()]).unwrap() {
+            Entry::Private(Plaintext::Literal(Literal::U64(amount), _)) => assert_eq!(*amount, balance - 110),
+            _ => panic!("Expected a 'credits.aleo' record"),
+        }
+
+        // Ensure a public fee has no change record.
+        let authorization = vm.authorize_fee_public(&caller_private_key, 100, 10, Field::rand(rng), rng).unwrap();
+        let (fee, change) = vm.execute_fee_authorization_with_change(authorization, None, rng).unwrap();
+        assert!(fee.is_fee_public());
+        assert!(change.is_none());
+    }
+
+    #[test]
+    fn test_execute_fee_authorization_with_audit() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM and records.
+        let (vm, records) = prepare_vm(rng).unwrap();
+
+        // Fetch the unspent record.
+        let (commitment, record) = records.first().unwrap();
+        let record = record.decrypt(&caller_view_key).unwrap();
+
+        // Execute a private fee, capturing the audit event.
+        let mut events = Vec::new();
+        let authorization =
+            vm.authorize_fee_private(&caller_private_key, record, 100, 10, Field::rand(rng), rng).unwrap();
+        let audit = |commitment, amount| events.push((commitment, amount));
+        let fee = vm.execute_fee_authorization_with_audit(authorization, None, audit, rng).unwrap();
+        assert!(fee.is_fee_private());
+        assert_eq!(events, [(*commitment, 110)]);
+
+        // Ensure a public fee is not audited.
+        let mut events = Vec::new();
+        let authorization = vm.authorize_fee_public(&caller_private_key, 100, 10, Field::rand(rng), rng).unwrap();
+        let audit = |commitment, amount| events.push((commitment, amount));
+        vm.execute_fee_authorization_with_audit(authorization, None, audit, rng).unwrap();
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_execute_fee_authorization_with_cap() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM and records.
+        let (vm, records) = prepare_vm(rng).unwrap();
+
+        // Fetch the unspent record.
+        let record = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
+
+        // Ensure a private fee above the cap is rejected.
+        let authorization =
+            vm.authorize_fee_private(&caller_private_key, record.clone(), 100, 10, Field::rand(rng), rng).unwrap();
+        let error = vm.execute_fee_authorization_with_cap(authorization, 109, None, rng).unwrap_err();
+        assert!(error.to_string().contains("exceeds the maximum fee of 109 microcredits"), "{error}");
+
+        // Ensure a private fee at the cap is executed.
+        let authorization =
+            vm.authorize_fee_private(&caller_private_key, record, 100, 10, Field::rand(rng), rng).unwrap();
+        let fee = vm.execute_fee_authorization_with_cap(authorization, 110, None, rng).unwrap();
+        assert_eq!(*fee.amount().unwrap(), 110);
+
+        // Ensure a public fee above the cap is rejected.
+        let authorization = vm.authorize_fee_public(&caller_private_key, 100, 10, Field::rand(rng), rng).unwrap();
+        assert!(vm.execute_fee_authorization_with_cap(authorization, 0, None, rng).is_err());
+    }
+
+    #[test]
+    fn test_execute_with_fee() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM and records.
+        let (vm, records) = prepare_vm(rng).unwrap();
+
+        // Fetch the unspent record.
+        let record = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
+
+        // Prepare the inputs.
+        let inputs = [
+            Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ]
+        .into_iter();
+
+        // Authorize the execution.
+        let authorization = vm.authorize(&caller_private_key, "credits.aleo", "transfer_public", inputs, rng).unwrap();
+
+        // Execute with a private fee.
+        let transaction =
+            vm.execute_with_fee(&caller_private_key, authorization, Some(record), 100_000, 0, None, rng).unwrap();
+
+        // Ensure the transaction contains one execution transition and one fee.
+        let Transaction::Execute(_, execution, Some(fee)) = &transaction else {
+            panic!("Expected an execute transaction with a fee");
+        };
+        assert_eq!(execution.len(), 1);
+        assert!(fee.is_fee_private());
+        // Ensure the execution and fee share the same global state root.
+        assert_eq!(execution.global_state_root(), fee.global_state_root());
+        // Ensure the transaction is valid.
+        vm.check_transaction(&transaction, None, rng).unwrap();
+    }
+
+    #[test]
+    fn test_spendable_records() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM and records.
+        let (vm, records) = prepare_vm(rng).unwrap();
+
+        // Ensure the genesis records are spendable.
+        let spendable = vm.spendable_records(&caller_view_key).unwrap();
+        assert_eq!(spendable.len(), records.len());
+
+        // Fetch the unspent record.
+        let (commitment, record) = records.iter().next().unwrap();
+        let record = record.decrypt(&caller_view_key).unwrap();
+        assert_eq!(spendable.get(commitment), Some(&record));
+
+        // Prepare the inputs.
+        let inputs = [
+            Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ]
+        .into_iter();
+
+        // Execute with a private fee, and add the transaction to the ledger.
+        let authorization = vm.authorize(&caller_private_key, "credits.aleo", "transfer_public", inputs, rng).unwrap();
+        let transaction =
+            vm.execute_with_fee(&caller_private_key, authorization, Some(record), 100_000, 0, None, rng).unwrap();
+        let next_block = crate::test_helpers::sample_next_block(&vm, &caller_private_key, &[transaction], rng).unwrap();
+        vm.add_next_block(&next_block).unwrap();
+
+        // Ensure the fee record is no longer spendable, and the change record is spendable.
+        let spendable = vm.spendable_records(&caller_view_key).unwrap();
+        assert_eq!(spendable.len(), records.len());
+        assert!(!spendable.contains_key(commitment));
+
+        // Ensure the records of another account are not returned.
+        let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        assert!(vm.spendable_records(&other_view_key).unwrap().is_empty());
+    }
+
     #[test]
     fn test_wide_nested_execution_cost() {
         // Initialize an RNG.
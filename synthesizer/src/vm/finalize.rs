@@ -327,7 +327,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                                 process
                                     .finalize_fee(state, store, fee)
                                     .and_then(|finalize| {
-                                        Transaction::from_fee(fee.clone()).map(|fee_tx| (fee_tx, finalize))
+                                        Transaction::from_fee_checked(fee.clone()).map(|fee_tx| (fee_tx, finalize))
                                     })
                                     .map(|(fee_tx, finalize)| {
                                         let rejected = Rejected::new_deployment(*program_owner, deployment);
@@ -393,7 +393,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                                 // Finalize the fee, to ensure it is valid.
                                 Some(fee) => {
                                     match process.finalize_fee(state, store, fee).and_then(|finalize| {
-                                        Transaction::from_fee(fee.clone()).map(|fee_tx| (fee_tx, finalize))
+                                        Transaction::from_fee_checked(fee.clone()).map(|fee_tx| (fee_tx, finalize))
                                     }) {
                                         Ok((fee_tx, finalize)) => {
                                             // Construct the rejected execution.
@@ -1626,7 +1626,7 @@ finalize transfer_public:
         match transaction {
             Transaction::Execute(_, execution, fee) => ConfirmedTransaction::RejectedExecute(
                 index,
-                Transaction::from_fee(fee.clone().unwrap()).unwrap(),
+                Transaction::from_fee_checked(fee.clone().unwrap()).unwrap(),
                 Rejected::new_execution(execution.clone()),
                 finalize.to_vec(),
             ),
@@ -2123,7 +2123,7 @@ function ped_hash:
             assert_eq!(confirmed_transactions.len(), 1);
             assert!(transaction.is_execute());
             if let Transaction::Execute(_, execution, fee) = transaction {
-                let fee_transaction = Transaction::from_fee(fee.unwrap()).unwrap();
+                let fee_transaction = Transaction::from_fee_checked(fee.unwrap()).unwrap();
                 let expected_confirmed_transaction = ConfirmedTransaction::RejectedExecute(
                     0,
                     fee_transaction,
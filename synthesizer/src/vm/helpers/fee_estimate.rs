@@ -0,0 +1,24 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// An estimate of a fee, computed by synthesizing the fee transition without proving it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FeeEstimate {
+    /// The fee amount (in microcredits), which is the sum of the base fee and priority fee.
+    pub amount_in_microcredits: u64,
+    /// The size of the fee transition (in bytes).
+    pub transition_size_in_bytes: usize,
+    /// The number of constraints in the fee circuit, excluding the inclusion circuits.
+    pub num_constraints: u64,
+}
@@ -15,6 +15,9 @@
 pub(crate) mod committee;
 pub use committee::*;
 
+mod fee_estimate;
+pub use fee_estimate::*;
+
 #[cfg(feature = "history")]
 mod history;
 #[cfg(feature = "history")]
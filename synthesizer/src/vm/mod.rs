@@ -23,9 +23,21 @@ mod verify;
 
 use crate::{cast_mut_ref, cast_ref, convert, process, Restrictions};
 use console::{
-    account::{Address, PrivateKey},
+    account::{Address, GraphKey, PrivateKey, ViewKey},
     network::prelude::*,
-    program::{Argument, Identifier, Literal, Locator, Plaintext, ProgramID, ProgramOwner, Record, Value},
+    program::{
+        Argument,
+        Ciphertext,
+        Entry,
+        Identifier,
+        Literal,
+        Locator,
+        Plaintext,
+        ProgramID,
+        ProgramOwner,
+        Record,
+        Value,
+    },
     types::{Field, Group, U64},
 };
 use ledger_block::{
@@ -46,7 +58,7 @@ use ledger_block::{
 use ledger_committee::Committee;
 use ledger_narwhal_data::Data;
 use ledger_puzzle::Puzzle;
-use ledger_query::Query;
+use ledger_query::{PinnedQuery, Query, QueryTrait};
 use ledger_store::{
     atomic_finalize,
     BlockStore,